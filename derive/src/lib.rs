@@ -0,0 +1,50 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
+
+/// Generate a lens for each field of a struct, as an associated function named after the field with a `lens_` prefix
+/// (so as not to collide with getters) and with the same visibility as the field.
+///
+/// ```ignore
+/// #[derive(Lens)]
+/// struct Data {
+///     screen: String,
+/// }
+///
+/// Label::bind_state(Data::lens_screen)
+/// ```
+#[proc_macro_derive(Lens)]
+pub fn derive_lens(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return error(&input, "Lens can only be derived for structs with named fields"),
+        },
+        _ => return error(&input, "Lens can only be derived for structs"),
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let lenses = fields.iter().map(|field| {
+        let (vis, ident, ty) = (&field.vis, &field.ident, &field.ty);
+        let lens = format_ident!("lens_{}", ident.as_ref().unwrap());
+        quote! {
+            #[allow(dead_code)]
+            #vis fn #lens(data: &mut Self) -> &mut #ty {
+                &mut data.#ident
+            }
+        }
+    });
+
+    TokenStream::from(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#lenses)*
+        }
+    })
+}
+
+fn error(input: &DeriveInput, msg: &str) -> TokenStream {
+    syn::Error::new_spanned(&input.ident, msg).to_compile_error().into()
+}
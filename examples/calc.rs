@@ -1,12 +1,12 @@
 use gui::{
-    widget::{Toggle, Button, Label, List},
-    layout::Direction,
+    widget::{Button, Label, Grid, grid::Track},
+    layout::Align,
     event::Click,
-    Window, Widget,
+    Window, Widget, Lens,
 };
 
 fn main() {
-    #[derive(Default)]
+    #[derive(Default, Lens)]
     struct Data {
         screen: String,
         second: f64,
@@ -28,54 +28,36 @@ fn main() {
         }
     }
 
+    let button = |label: &'static str, f: fn(&mut Data)| Button::<Data>::default_state()
+        .containing(Label::<Data>::with_state(label).padded(16.0).centered())
+        .on(Click, move |ctx| f(ctx.data));
     let num_button = |n: &'static str| Button::<Data>::default_state()
-        .containing(Label::<Data>::with_state(n).padded(16.0))
-        .on(Click, move |ctx| ctx.data.screen.push_str(&n))
-        .padded(8.0);
+        .containing(Label::<Data>::with_state(n).padded(16.0).centered())
+        .on(Click, move |ctx| ctx.data.screen.push_str(n));
 
-    let ui = List::<Data>::vertical()
-        .push(Button::<Data>::default_state()
-            .containing(Label::<Data>::bind_state(|d| &mut d.screen).padded(16.0))
-            .padded(8.0))
-        .push(List::<Data>::horizontal()
-            .push(num_button("1"))
-            .push(num_button("2"))
-            .push(num_button("3"))
-            .push(Button::<Data>::default_state()
-                .containing(Label::<Data>::with_state("+").padded(16.0))
-                .on(Click, |ctx| ctx.data.start_op(|x, y| x + y))
-                .padded(8.0)))
-        .push(List::<Data>::horizontal()
-            .push(num_button("4"))
-            .push(num_button("5"))
-            .push(num_button("6"))
-            .push(Button::<Data>::default_state()
-                .containing(Label::<Data>::with_state("-").padded(16.0))
-                .on(Click, |ctx| ctx.data.start_op(|x, y| x - y))
-                .padded(8.0)))
-        .push(List::<Data>::horizontal()
-            .push(num_button("7"))
-            .push(num_button("8"))
-            .push(num_button("9"))
-            .push(Button::<Data>::default_state()
-                .containing(Label::<Data>::with_state("*").padded(16.0))
-                .on(Click, |ctx| ctx.data.start_op(|x, y| x * y))
-                .padded(8.0)))
-        .push(List::<Data>::horizontal()
-            .push(Button::<Data>::default_state()
-                .containing(Label::<Data>::with_state("C").padded(16.0))
-                .on(Click, |ctx| ctx.data.screen.clear())
-                .padded(8.0))
-            .push(num_button("0"))
-            .push(Button::<Data>::default_state()
-                .containing(Label::<Data>::with_state("=").padded(16.0))
-                .on(Click, |ctx| ctx.data.calc())
-                .padded(8.0))
-            .push(Button::<Data>::default_state()
-                .containing(Label::<Data>::with_state("/").padded(16.0))
-                .on(Click, |ctx| ctx.data.start_op(|x, y| x / y))
-                .padded(8.0)))
-        .padded(8.0);
+    let ui = Grid::<Data>::new()
+        .columns([Track::Fraction(1.0); 4])
+        .rows([Track::Auto, Track::Fraction(1.0), Track::Fraction(1.0), Track::Fraction(1.0), Track::Fraction(1.0)])
+        .gap(16.0)
+        .span(0, 0, 1, 4, Button::<Data>::default_state()
+            .containing(Label::<Data>::bind_state(Data::lens_screen).padded(16.0).align(Align::End, Align::Center)))
+        .cell(1, 0, num_button("1"))
+        .cell(1, 1, num_button("2"))
+        .cell(1, 2, num_button("3"))
+        .cell(1, 3, button("+", |d| d.start_op(|x, y| x + y)))
+        .cell(2, 0, num_button("4"))
+        .cell(2, 1, num_button("5"))
+        .cell(2, 2, num_button("6"))
+        .cell(2, 3, button("-", |d| d.start_op(|x, y| x - y)))
+        .cell(3, 0, num_button("7"))
+        .cell(3, 1, num_button("8"))
+        .cell(3, 2, num_button("9"))
+        .cell(3, 3, button("*", |d| d.start_op(|x, y| x * y)))
+        .cell(4, 0, button("C", |d| d.screen.clear()))
+        .cell(4, 1, num_button("0"))
+        .cell(4, 2, button("=", |d| d.calc()))
+        .cell(4, 3, button("/", |d| d.start_op(|x, y| x / y)))
+        .padded(16.0);
 
     Window::new(ui).run(Data::default())
 }
@@ -0,0 +1,170 @@
+use crate::{
+    draw::{Fill, Color},
+    canvas::Primitive,
+    text::TextMetrics,
+};
+use std::{
+    cell::RefCell,
+    io::{self, Write},
+    ops::Deref,
+    rc::Rc,
+};
+use cairo::{Format, ImageSurface, Context, IoError};
+use piet::{kurbo::{Rect, Line}, FontFamily, RenderContext, Text, TextLayout, TextLayoutBuilder};
+use piet_cairo::{CairoRenderContext, CairoText, CairoTextLayout};
+
+/// The font size of text, relative to the line height it's drawn at.
+const FONT_SCALE: f64 = 0.8;
+
+/// Lays out text with pango, the same way that [`CairoRenderer`] draws it.
+pub struct CairoMetrics {
+    text: RefCell<CairoText>,
+    font: FontFamily,
+}
+
+impl CairoMetrics {
+    pub fn new() -> Self {
+        let mut text = CairoText::new();
+        let font = text.font_family("Open Sans").unwrap_or(FontFamily::SANS_SERIF);
+        Self {
+            text: RefCell::new(text),
+            font,
+        }
+    }
+
+    fn layout(&self, text: &str, height: f32, col: Color) -> CairoTextLayout {
+        self.text
+            .borrow_mut()
+            .new_text_layout(text.to_string())
+            .font(self.font.clone(), height as f64 * FONT_SCALE)
+            .text_color(piet::Color::rgba8(col.r, col.g, col.b, col.a))
+            .build()
+            .unwrap()
+    }
+}
+
+impl Default for CairoMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TextMetrics for CairoMetrics {
+    fn measure(&self, text: &str, height: f32) -> [f32; 2] {
+        let layout = self.layout(text, height, Color::BLACK);
+        // Trailing spaces still move the caret along
+        [layout.trailing_whitespace_width() as f32, height.max(layout.size().height as f32)]
+    }
+
+    fn carets(&self, text: &str, height: f32) -> Vec<f32> {
+        // Hit testing one layout of the whole line accounts for kerning, and is far cheaper than measuring each prefix
+        let layout = self.layout(text, height, Color::BLACK);
+        text.char_indices()
+            .map(|(i, _)| layout.hit_test_text_position(i).point.x as f32)
+            .chain(std::iter::once(layout.trailing_whitespace_width() as f32))
+            .collect()
+    }
+}
+
+/// Rasterizes canvas primitives into an in-memory ARGB32 image using cairo.
+pub struct CairoRenderer {
+    surf: ImageSurface,
+    size: [usize; 2],
+    metrics: Rc<CairoMetrics>,
+}
+
+impl CairoRenderer {
+    pub fn new(size: [usize; 2]) -> Self {
+        Self {
+            surf: ImageSurface::create(Format::ARgb32, size[0] as i32, size[1] as i32).unwrap(),
+            size,
+            metrics: Rc::new(CairoMetrics::new()),
+        }
+    }
+
+    /// The metrics used to lay out the text this renderer draws.
+    pub fn metrics(&self) -> Rc<CairoMetrics> {
+        self.metrics.clone()
+    }
+
+    pub fn size(&self) -> [usize; 2] {
+        self.size
+    }
+
+    pub fn resize(&mut self, size: [usize; 2]) {
+        if self.size != size {
+            self.surf = ImageSurface::create(Format::ARgb32, size[0] as i32, size[1] as i32).unwrap();
+            self.size = size;
+        }
+    }
+
+    pub fn render(&mut self, primitives: &[Primitive]) {
+        let cx = Context::new(&self.surf).unwrap();
+        let mut rcx = CairoRenderContext::new(&cx);
+        rcx.clear(None, piet::Color::TRANSPARENT);
+
+        for prim in primitives {
+            match prim {
+                Primitive::Rect { rect, fill, radius } => {
+                    let brush = match fill {
+                        Fill::Color(col) => rcx.solid_brush(piet::Color::rgba8(col.r, col.g, col.b, col.a)),
+                    };
+                    let rect = Rect::new(rect.x as f64, rect.y as f64, (rect.x + rect.w) as f64, (rect.y + rect.h) as f64);
+                    rcx.fill(rect.to_rounded_rect(*radius as f64), &brush);
+                },
+                Primitive::Line { line, stroke } => {
+                    let brush = match &stroke.fill {
+                        Fill::Color(col) => rcx.solid_brush(piet::Color::rgba8(col.r, col.g, col.b, col.a)),
+                    };
+                    rcx.stroke(Line::new(
+                        line.start.map(|e| e as f64).into_tuple(),
+                        line.end.map(|e| e as f64).into_tuple(),
+                    ), &brush, stroke.width as f64);
+                },
+                Primitive::Text { pos, text, height, col } => {
+                    let layout = self.metrics.layout(text, *height, *col);
+                    rcx.draw_text(&layout, pos.map(|e| e as f64).into_tuple());
+                },
+                Primitive::PushClip { rect } => {
+                    rcx.save().unwrap();
+                    rcx.clip(Rect::new(rect.x as f64, rect.y as f64, (rect.x + rect.w) as f64, (rect.y + rect.h) as f64));
+                },
+                Primitive::PopClip => rcx.restore().unwrap(),
+            }
+        }
+
+        rcx.finish().unwrap();
+    }
+
+    /// Access the raw pixels of the last rendered frame, as native-endian `0xAARRGGBB` words.
+    pub fn with_pixels<R>(&mut self, f: impl FnOnce(&[u32]) -> R) -> R {
+        let [w, h] = self.size;
+        let data = self.surf.data().unwrap();
+        let pixels = data
+            .deref()
+            .chunks_exact(4)
+            .take(w * h)
+            .map(|px| u32::from_ne_bytes([px[0], px[1], px[2], px[3]]))
+            .collect::<Vec<_>>();
+        f(&pixels)
+    }
+
+    /// The pixels of the last rendered frame, as non-premultiplied RGBA bytes in row-major order.
+    pub fn rgba(&mut self) -> Vec<u8> {
+        self.with_pixels(|pixels| pixels
+            .iter()
+            .flat_map(|px| {
+                let [a, r, g, b] = px.to_be_bytes();
+                let unmul = |c: u8| if a == 0 { 0 } else { (c as u32 * 255 / a as u32).min(255) as u8 };
+                [unmul(r), unmul(g), unmul(b), a]
+            })
+            .collect())
+    }
+
+    pub fn write_png(&self, w: &mut impl Write) -> io::Result<()> {
+        self.surf.write_to_png(w).map_err(|e| match e {
+            IoError::Io(e) => e,
+            e => io::Error::other(e.to_string()),
+        })
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{
+    layout::Bounds,
+    Env, Widget, Theme,
+};
+use super::CairoRenderer;
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+/// Renders widget trees offscreen, without opening a window.
+///
+/// Widgets are drawn with the default theme, or the one given to [`Headless::render_with_theme`].
+///
+/// ```ignore
+/// let mut frame = Headless::render(ui, [640, 480], &mut data);
+/// frame.save_png("ui.png")?;
+/// ```
+pub struct Headless {
+    renderer: CairoRenderer,
+}
+
+impl Headless {
+    pub fn render<'a, D>(root: impl Widget<'a, D> + 'a, size: [usize; 2], data: &mut D) -> Self {
+        Self::render_with_theme(root, size, data, Theme::default())
+    }
+
+    pub fn render_with_theme<'a, D>(
+        root: impl Widget<'a, D> + 'a,
+        size: [usize; 2],
+        data: &mut D,
+        theme: Theme,
+    ) -> Self {
+        let mut renderer = CairoRenderer::new(size);
+        let env = Env::new(renderer.metrics()).with_theme(theme);
+
+        let mut element = root.finish();
+        element.update(data);
+        element.get_layout_req(data, &env);
+        element.fit_bounds(Bounds::global([size[0] as f32, size[1] as f32]));
+
+        let canvas = super::draw_frame(&mut element, data, &env);
+        renderer.render(&canvas.primitives);
+
+        Self { renderer }
+    }
+
+    pub fn size(&self) -> [usize; 2] {
+        self.renderer.size()
+    }
+
+    /// The rendered image as non-premultiplied RGBA bytes in row-major order.
+    pub fn rgba(&mut self) -> Vec<u8> {
+        self.renderer.rgba()
+    }
+
+    pub fn write_png(&self, w: &mut impl Write) -> io::Result<()> {
+        self.renderer.write_png(w)
+    }
+
+    pub fn save_png(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut file = BufWriter::new(File::create(path)?);
+        self.write_png(&mut file)?;
+        file.flush()
+    }
+}
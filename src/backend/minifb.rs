@@ -0,0 +1,275 @@
+use crate::{
+    canvas::Primitive,
+    event::{KeyPressed, KeyReleased},
+    text::TextMetrics,
+    Event, MouseButton, Key, Modifiers,
+};
+use super::{Backend, CairoRenderer};
+use std::{cell::RefCell, rc::Rc};
+
+/// A desktop window created with minifb, rasterized using cairo.
+pub struct MinifbBackend {
+    win: minifb::Window,
+    renderer: CairoRenderer,
+    last_mouse_pos: Option<(f32, f32)>,
+    mouse_down: [bool; 3],
+    text: Rc<RefCell<String>>,
+}
+
+/// Collects typed characters from minifb until the next call to `poll_events`.
+struct TextInput(Rc<RefCell<String>>);
+
+impl minifb::InputCallback for TextInput {
+    fn add_char(&mut self, uni_char: u32) {
+        if let Some(c) = std::char::from_u32(uni_char).filter(|c| !c.is_control()) {
+            self.0.borrow_mut().push(c);
+        }
+    }
+}
+
+impl MinifbBackend {
+    pub fn new() -> Self {
+        let mut win = minifb::Window::new(
+            "Test",
+            640,
+            480,
+            minifb::WindowOptions {
+                resize: true,
+                ..Default::default()
+            },
+        ).unwrap();
+        let (w, h) = win.get_size();
+
+        let text = Rc::new(RefCell::new(String::new()));
+        win.set_input_callback(Box::new(TextInput(text.clone())));
+
+        Self {
+            win,
+            renderer: CairoRenderer::new([w, h]),
+            last_mouse_pos: None,
+            mouse_down: [false; 3],
+            text,
+        }
+    }
+}
+
+impl Default for MinifbBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Backend for MinifbBackend {
+    fn is_open(&self) -> bool {
+        self.win.is_open()
+    }
+
+    fn size(&self) -> [usize; 2] {
+        let (w, h) = self.win.get_size();
+        [w, h]
+    }
+
+    fn text_metrics(&self) -> Rc<dyn TextMetrics> {
+        self.renderer.metrics()
+    }
+
+    fn poll_events(&mut self, events: &mut Vec<Event>) {
+        const BUTTONS: [(minifb::MouseButton, MouseButton); 3] = [
+            (minifb::MouseButton::Left, MouseButton::Left),
+            (minifb::MouseButton::Middle, MouseButton::Middle),
+            (minifb::MouseButton::Right, MouseButton::Right),
+        ];
+
+        let mouse_pos = self.win.get_mouse_pos(minifb::MouseMode::Pass);
+        // If the cursor has left the window, button changes happen wherever it was last seen
+        let button_pos = mouse_pos.or(self.last_mouse_pos);
+        if mouse_pos != self.last_mouse_pos {
+            if let Some(pos) = mouse_pos {
+                events.push(Event::CursorMove([pos.0, pos.1]));
+                if let Some(last) = self.last_mouse_pos {
+                    for (i, (_, button)) in BUTTONS.iter().enumerate() {
+                        if self.mouse_down[i] {
+                            events.push(Event::Drag {
+                                pos: [pos.0, pos.1],
+                                delta: [pos.0 - last.0, pos.1 - last.1],
+                                button: *button,
+                            });
+                        }
+                    }
+                }
+            }
+            self.last_mouse_pos = mouse_pos;
+        }
+
+        for (i, (mf_button, button)) in BUTTONS.iter().enumerate() {
+            let is_down = self.win.get_mouse_down(*mf_button);
+            if let Some(pos) = button_pos.filter(|_| is_down != self.mouse_down[i]) {
+                let pos = [pos.0, pos.1];
+                if is_down {
+                    events.push(Event::MouseDown(pos, *button));
+                    if *button == MouseButton::Left {
+                        events.push(Event::Click(pos, *button));
+                    }
+                } else {
+                    events.push(Event::MouseUp(pos, *button));
+                }
+            }
+            self.mouse_down[i] = is_down;
+        }
+
+        if let (Some(pos), Some(delta)) = (mouse_pos, self.win.get_scroll_wheel()) {
+            events.push(Event::Scroll([pos.0, pos.1], [delta.0, delta.1]));
+        }
+
+        let modifiers = Modifiers {
+            shift: self.win.is_key_down(minifb::Key::LeftShift) || self.win.is_key_down(minifb::Key::RightShift),
+            ctrl: self.win.is_key_down(minifb::Key::LeftCtrl) || self.win.is_key_down(minifb::Key::RightCtrl),
+            alt: self.win.is_key_down(minifb::Key::LeftAlt) || self.win.is_key_down(minifb::Key::RightAlt),
+            logo: self.win.is_key_down(minifb::Key::LeftSuper) || self.win.is_key_down(minifb::Key::RightSuper),
+        };
+        let first_presses = self.win.get_keys_pressed(minifb::KeyRepeat::No);
+        for key in self.win.get_keys_pressed(minifb::KeyRepeat::Yes) {
+            events.push(Event::KeyPress(KeyPressed {
+                key: convert_key(key),
+                modifiers,
+                repeat: !first_presses.contains(&key),
+            }));
+        }
+        for key in self.win.get_keys_released() {
+            events.push(Event::KeyRelease(KeyReleased {
+                key: convert_key(key),
+                modifiers,
+            }));
+        }
+
+        let text = std::mem::take(&mut *self.text.borrow_mut());
+        if !text.is_empty() {
+            events.push(Event::Text(text));
+        }
+    }
+
+    fn render(&mut self, primitives: &[Primitive]) {
+        let size = self.size();
+        self.renderer.resize(size);
+        self.renderer.render(primitives);
+
+        let win = &mut self.win;
+        self.renderer.with_pixels(|pixels| win.update_with_buffer(pixels, size[0], size[1]).unwrap());
+    }
+
+    fn idle(&mut self) {
+        self.win.update();
+    }
+}
+
+fn convert_key(key: minifb::Key) -> Key {
+    match key {
+        minifb::Key::Key0 => Key::Key0,
+        minifb::Key::Key1 => Key::Key1,
+        minifb::Key::Key2 => Key::Key2,
+        minifb::Key::Key3 => Key::Key3,
+        minifb::Key::Key4 => Key::Key4,
+        minifb::Key::Key5 => Key::Key5,
+        minifb::Key::Key6 => Key::Key6,
+        minifb::Key::Key7 => Key::Key7,
+        minifb::Key::Key8 => Key::Key8,
+        minifb::Key::Key9 => Key::Key9,
+        minifb::Key::A => Key::A,
+        minifb::Key::B => Key::B,
+        minifb::Key::C => Key::C,
+        minifb::Key::D => Key::D,
+        minifb::Key::E => Key::E,
+        minifb::Key::F => Key::F,
+        minifb::Key::G => Key::G,
+        minifb::Key::H => Key::H,
+        minifb::Key::I => Key::I,
+        minifb::Key::J => Key::J,
+        minifb::Key::K => Key::K,
+        minifb::Key::L => Key::L,
+        minifb::Key::M => Key::M,
+        minifb::Key::N => Key::N,
+        minifb::Key::O => Key::O,
+        minifb::Key::P => Key::P,
+        minifb::Key::Q => Key::Q,
+        minifb::Key::R => Key::R,
+        minifb::Key::S => Key::S,
+        minifb::Key::T => Key::T,
+        minifb::Key::U => Key::U,
+        minifb::Key::V => Key::V,
+        minifb::Key::W => Key::W,
+        minifb::Key::X => Key::X,
+        minifb::Key::Y => Key::Y,
+        minifb::Key::Z => Key::Z,
+        minifb::Key::F1 => Key::F1,
+        minifb::Key::F2 => Key::F2,
+        minifb::Key::F3 => Key::F3,
+        minifb::Key::F4 => Key::F4,
+        minifb::Key::F5 => Key::F5,
+        minifb::Key::F6 => Key::F6,
+        minifb::Key::F7 => Key::F7,
+        minifb::Key::F8 => Key::F8,
+        minifb::Key::F9 => Key::F9,
+        minifb::Key::F10 => Key::F10,
+        minifb::Key::F11 => Key::F11,
+        minifb::Key::F12 => Key::F12,
+        minifb::Key::F13 => Key::F13,
+        minifb::Key::F14 => Key::F14,
+        minifb::Key::F15 => Key::F15,
+        minifb::Key::Down => Key::Down,
+        minifb::Key::Left => Key::Left,
+        minifb::Key::Right => Key::Right,
+        minifb::Key::Up => Key::Up,
+        minifb::Key::Apostrophe => Key::Apostrophe,
+        minifb::Key::Backquote => Key::Backquote,
+        minifb::Key::Backslash => Key::Backslash,
+        minifb::Key::Comma => Key::Comma,
+        minifb::Key::Equal => Key::Equal,
+        minifb::Key::LeftBracket => Key::LeftBracket,
+        minifb::Key::Minus => Key::Minus,
+        minifb::Key::Period => Key::Period,
+        minifb::Key::RightBracket => Key::RightBracket,
+        minifb::Key::Semicolon => Key::Semicolon,
+        minifb::Key::Slash => Key::Slash,
+        minifb::Key::Backspace => Key::Backspace,
+        minifb::Key::Delete => Key::Delete,
+        minifb::Key::End => Key::End,
+        minifb::Key::Enter => Key::Enter,
+        minifb::Key::Escape => Key::Escape,
+        minifb::Key::Home => Key::Home,
+        minifb::Key::Insert => Key::Insert,
+        minifb::Key::Menu => Key::Menu,
+        minifb::Key::PageDown => Key::PageDown,
+        minifb::Key::PageUp => Key::PageUp,
+        minifb::Key::Pause => Key::Pause,
+        minifb::Key::Space => Key::Space,
+        minifb::Key::Tab => Key::Tab,
+        minifb::Key::NumLock => Key::NumLock,
+        minifb::Key::CapsLock => Key::CapsLock,
+        minifb::Key::ScrollLock => Key::ScrollLock,
+        minifb::Key::LeftShift => Key::LeftShift,
+        minifb::Key::RightShift => Key::RightShift,
+        minifb::Key::LeftCtrl => Key::LeftCtrl,
+        minifb::Key::RightCtrl => Key::RightCtrl,
+        minifb::Key::LeftAlt => Key::LeftAlt,
+        minifb::Key::RightAlt => Key::RightAlt,
+        minifb::Key::LeftSuper => Key::LeftSuper,
+        minifb::Key::RightSuper => Key::RightSuper,
+        minifb::Key::NumPad0 => Key::NumPad0,
+        minifb::Key::NumPad1 => Key::NumPad1,
+        minifb::Key::NumPad2 => Key::NumPad2,
+        minifb::Key::NumPad3 => Key::NumPad3,
+        minifb::Key::NumPad4 => Key::NumPad4,
+        minifb::Key::NumPad5 => Key::NumPad5,
+        minifb::Key::NumPad6 => Key::NumPad6,
+        minifb::Key::NumPad7 => Key::NumPad7,
+        minifb::Key::NumPad8 => Key::NumPad8,
+        minifb::Key::NumPad9 => Key::NumPad9,
+        minifb::Key::NumPadDot => Key::NumPadDot,
+        minifb::Key::NumPadSlash => Key::NumPadSlash,
+        minifb::Key::NumPadAsterisk => Key::NumPadAsterisk,
+        minifb::Key::NumPadMinus => Key::NumPadMinus,
+        minifb::Key::NumPadPlus => Key::NumPadPlus,
+        minifb::Key::NumPadEnter => Key::NumPadEnter,
+        _ => Key::Unknown,
+    }
+}
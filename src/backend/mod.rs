@@ -1,177 +1,167 @@
+#[cfg(feature = "cairo")]
+pub mod cairo;
+#[cfg(feature = "cairo")]
+pub mod headless;
+#[cfg(feature = "minifb")]
+pub mod minifb;
+
+#[cfg(feature = "cairo")]
+pub use self::{
+    cairo::{CairoRenderer, CairoMetrics},
+    headless::Headless,
+};
+#[cfg(feature = "minifb")]
+pub use self::minifb::MinifbBackend;
+
 use crate::{
-    draw::Fill,
     layout::Bounds,
     canvas::{Primitive, Canvas},
-    Widget, Event, Element, MouseButton, Response,
-};
-use std::{
-    convert::{TryFrom, TryInto},
-    ops::Deref,
+    text::{TextMetrics, FixedMetrics},
+    theme::Theme,
+    Env, Widget, Event, Element, State,
 };
-use cairo::{Format, ImageSurface, Context};
-use piet::{kurbo::{Rect, Line}, RenderContext, Text, TextLayoutBuilder, TextAttribute};
-use piet_cairo::CairoRenderContext;
+use std::rc::Rc;
+
+/// A host that a [`Window`] can be driven by.
+///
+/// A backend is both a source of events and a sink for the primitives that widgets emit when drawing.
+pub trait Backend {
+    /// Whether the host is still open. [`Window::run`] returns once this becomes `false`.
+    fn is_open(&self) -> bool;
+
+    /// The current size of the drawable area, in pixels.
+    fn size(&self) -> [usize; 2];
+
+    /// Collect any events that have occurred since the last call.
+    fn poll_events(&mut self, events: &mut Vec<Event>);
+
+    /// Render a frame made up of the given primitives.
+    fn render(&mut self, primitives: &[Primitive]);
+
+    /// Called in place of `render` when nothing needs to be redrawn.
+    fn idle(&mut self) {}
+
+    /// Metrics that match how `render` draws text.
+    fn text_metrics(&self) -> Rc<dyn TextMetrics> {
+        Rc::new(FixedMetrics)
+    }
+}
 
-pub struct Window<'a, D> {
-    win: minifb::Window,
+pub struct Window<'a, D, B> {
+    backend: B,
     element: Element<'a, D>,
-    font_data: Vec<u8>,
+    theme: State<'a, D, Theme>,
 }
 
-impl<'a, D> Window<'a, D> {
+#[cfg(feature = "minifb")]
+impl<'a, D> Window<'a, D, MinifbBackend> {
     pub fn new(root: impl Widget<'a, D> + 'a) -> Self {
+        Self::with_backend(MinifbBackend::new(), root)
+    }
+}
+
+impl<'a, D, B: Backend> Window<'a, D, B> {
+    pub fn with_backend(backend: B, root: impl Widget<'a, D> + 'a) -> Self {
         Self {
-            win: minifb::Window::new(
-                "Test",
-                640,
-                480,
-                minifb::WindowOptions {
-                    resize: true,
-                    ..Default::default()
-                },
-            ).unwrap(),
+            backend,
             element: root.finish(),
-            font_data: include_bytes!("../../data/OpenSans-Regular.ttf").to_vec(),
+            theme: State::Inner(Theme::default()),
         }
     }
 
-    pub fn run(mut self, mut data: D) {
-        let mut last_mouse_pos = None;
-        let mut last_size = (0, 0);
-        let mut mouse_down = false;
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = State::Inner(theme);
+        self
+    }
+
+    /// Take the theme from the application data, so that it can be switched at runtime.
+    pub fn bind_theme(mut self, f: impl for<'b> FnMut(&'b mut D) -> &'b mut Theme + 'a) -> Self {
+        self.theme = State::Bind(Box::new(f), None);
+        self
+    }
+
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
 
-        //let mut buf = Vec::new();
-        let mut surf = ImageSurface::create(Format::ARgb32, self.win.get_size().0 as i32, self.win.get_size().1 as i32).unwrap();
-        let mut maybe_font = None;
+    pub fn backend_mut(&mut self) -> &mut B {
+        &mut self.backend
+    }
+
+    pub fn run(mut self, mut data: D) {
+        let mut last_size = None;
         let mut redraw = true;
+        // Whether the data may have changed since the widgets last looked at it
+        let mut changed = true;
 
-        while self.win.is_open() {
-            let (w, h) = self.win.get_size();
+        let mut env = Env::new(self.backend.text_metrics());
+
+        while self.backend.is_open() {
+            let [w, h] = self.backend.size();
 
             // Collect events
             let mut events = Vec::new();
-            let mouse_pos = self.win.get_mouse_pos(minifb::MouseMode::Pass);
-            if mouse_pos != last_mouse_pos {
-                mouse_pos.map(|mouse_pos| events.push(Event::CursorMove([mouse_pos.0, mouse_pos.1])));
-                last_mouse_pos = mouse_pos;
-            }
-            mouse_down = if self.win.get_mouse_down(minifb::MouseButton::Left) {
-                if !mouse_down {
-                    if let Some(mouse_pos) = mouse_pos {
-                        events.push(Event::Click([mouse_pos.0, mouse_pos.1], MouseButton::Left));
-                    }
-                }
-                true
-            } else {
-                false
-            };
+            self.backend.poll_events(&mut events);
 
-            if last_size != self.win.get_size() {
-                last_size = self.win.get_size();
-                surf = ImageSurface::create(Format::ARgb32, self.win.get_size().0 as i32, self.win.get_size().1 as i32).unwrap();
+            if last_size != Some([w, h]) {
+                last_size = Some([w, h]);
                 redraw = true;
-                self.element.get_layout_req();
-                self.element.fit_bounds(Bounds::global([w as f32, h as f32]));
             }
 
             for event in events {
-                let mut resp = Response {
-                    redraw: false,
-                };
-                self.element.handle(&mut data, &event, &mut resp);
-                redraw |= resp.redraw;
+                // An earlier event may have changed the data, and this one should reach the widgets that now show it
+                if std::mem::take(&mut changed) && self.update(&mut data, &mut env) {
+                    self.layout(&mut data, &env, [w as f32, h as f32]);
+                    redraw = true;
+                }
+
+                let resp = self.element.dispatch(&mut data, &env, &event);
+                redraw |= resp.needs_redraw();
+                changed |= resp.data_changed();
+            }
+
+            // Only widgets whose state was affected get laid out and drawn again, the rest reuse what they last drew
+            if std::mem::take(&mut changed) {
+                redraw |= self.update(&mut data, &mut env);
             }
 
             if redraw {
-                //buf.resize_with(w * h * 4, || 0);
-
-                // Collect widget primitives
-                let mut prim_canvas = Canvas::default();
-                self.element.draw(&mut data, &mut prim_canvas);
-
-                // Draw primitives
-                //let mut canvas = andrew::Canvas::new(&mut buf, w, h, w * 4, andrew::Endian::native());
-                //canvas.pixel_size = 4;
-
-                {
-                    let cx = Context::new(&surf).unwrap();
-                    let mut rcx = CairoRenderContext::new(&cx);
-                    rcx.clear(None, piet::Color::grey(0.5));
-
-                        for prim in prim_canvas.primitives {
-                        match prim {
-                            Primitive::Rect { rect, fill } => {
-                                let brush = match fill {
-                                    Fill::Color(col) => rcx.solid_brush(piet::Color::rgba8(col.r, col.g, col.b, col.a)),
-                                };
-                                rcx.fill(Rect::new(rect.x as f64, rect.y as f64, (rect.x + rect.w) as f64, (rect.y + rect.h) as f64), &brush);
-
-                                // canvas
-                                //     .draw(&andrew::shapes::rectangle::Rectangle::new(
-                                //         rect.position().map(|e| e as usize).into_tuple(),
-                                //         rect.extent().map(|e| e as usize).into_tuple(),
-                                //         None,
-                                //         Some(match fill {
-                                //             Fill::Color(col) => [col.a, col.r, col.g, col.b],
-                                //         }),
-                                //     ))
-                            },
-                            Primitive::Line { line, stroke } => {
-                                let brush = match stroke.fill {
-                                    Fill::Color(col) => rcx.solid_brush(piet::Color::rgba8(col.r, col.g, col.b, col.a)),
-                                };
-                                rcx.stroke(Line::new(
-                                    line.start.map(|e| e as f64).into_tuple(),
-                                    line.end.map(|e| e as f64).into_tuple(),
-                                ), &brush, stroke.width as f64);
-                                // canvas
-                                //     .draw(&andrew::line::Line::new(
-                                //         line.start.map(|e| e as usize).into_tuple(),
-                                //         line.end.map(|e| e as usize).into_tuple(),
-                                //         match stroke.fill {
-                                //             Fill::Color(col) => [col.a, col.r, col.g, col.b],
-                                //         },
-                                //         true,
-                                //     ))
-                            },
-                            Primitive::Text { pos, text, height, col } => {
-                                let mut text_state = rcx.text();
-
-                                let font = maybe_font.take().unwrap_or_else(|| {
-                                    text_state.font_family("Open Sans").unwrap()
-                                });
-
-                                let layout = text_state
-                                    .new_text_layout(text)
-                                    .text_color(piet::Color::rgba8(col.r, col.g, col.b, col.a))
-                                    .default_attribute(TextAttribute::FontSize(16.0))
-                                    .build()
-                                    .unwrap();
-
-                                rcx.draw_text(&layout, pos.map(|e| e as f64).into_tuple());
-
-                                maybe_font = Some(font);
-
-                                // canvas
-                                //     .draw(&andrew::text::Text::new(
-                                //         pos.map(|e| e as usize).into_tuple(),
-                                //         [col.a, col.r, col.g, col.b],
-                                //         &self.font_data,
-                                //         height,
-                                //         1.0,
-                                //         text,
-                                //     ))
-                            },
-                        }
-                    }
-                }
+                // Content may have changed size, along with the window and theme
+                self.layout(&mut data, &env, [w as f32, h as f32]);
 
-                self.win.update_with_buffer(unsafe { std::mem::transmute(surf.data().unwrap().deref()) }, w, h).unwrap();
+                let canvas = draw_frame(&mut self.element, &mut data, &env);
+                self.backend.render(&canvas.primitives);
 
                 redraw = false;
             } else {
-                self.win.update();
+                self.backend.idle();
             }
         }
     }
 }
+
+impl<'a, D, B> Window<'a, D, B> {
+    /// Bring the widgets and theme up to date with data that may have changed, returning whether anything needs to be
+    /// laid out and drawn again.
+    fn update(&mut self, data: &mut D, env: &mut Env) -> bool {
+        let theme = self.theme.get_mut(data);
+        let theme_changed = *theme != *env.theme();
+        if theme_changed {
+            *env = env.clone().with_theme(theme.clone());
+        }
+        self.element.update(data) | theme_changed
+    }
+
+    fn layout(&mut self, data: &mut D, env: &Env, size: [f32; 2]) {
+        self.element.get_layout_req(data, env);
+        self.element.fit_bounds(Bounds::global(size));
+    }
+}
+
+/// Collect the primitives of a whole frame, over the theme's background.
+fn draw_frame<D>(element: &mut Element<D>, data: &mut D, env: &Env) -> Canvas {
+    let mut canvas = Canvas::default();
+    canvas.bounded(element.bounds()).fill(env.theme().palette.background);
+    element.draw(data, env, &mut canvas);
+    canvas
+}
@@ -3,11 +3,62 @@ use crate::{
     layout::Bounds,
 };
 use vek::*;
+use std::fmt;
 
+#[derive(Clone, Debug, PartialEq)]
 pub enum Primitive {
-    Rect { rect: Rect<f32, f32>, fill: Fill },
+    Rect { rect: Rect<f32, f32>, fill: Fill, radius: f32 },
     Line { line: LineSegment2<f32>, stroke: Stroke },
     Text { pos: Vec2<f32>, text: String, height: f32, col: Color },
+    /// Confine all following primitives to the rectangle (and any outer clip), until the matching `PopClip`.
+    PushClip { rect: Rect<f32, f32> },
+    PopClip,
+}
+
+/// Formats a coordinate to at most two decimal places, so that dumps are stable across tiny float differences.
+struct Coord(f32);
+
+impl fmt::Display for Coord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = format!("{:.2}", self.0);
+        let s = s.trim_end_matches('0').trim_end_matches('.');
+        f.write_str(if s == "-0" { "0" } else { s })
+    }
+}
+
+impl fmt::Display for Primitive {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Primitive::Rect { rect, fill, radius } => {
+                write!(
+                    f,
+                    "rect pos=({}, {}) size=({}, {}) fill={}",
+                    Coord(rect.x), Coord(rect.y), Coord(rect.w), Coord(rect.h), fill,
+                )?;
+                if *radius > 0.0 {
+                    write!(f, " radius={}", Coord(*radius))?;
+                }
+                Ok(())
+            },
+            Primitive::Line { line, stroke } => write!(
+                f,
+                "line from=({}, {}) to=({}, {}) width={} fill={}",
+                Coord(line.start.x), Coord(line.start.y), Coord(line.end.x), Coord(line.end.y),
+                Coord(stroke.width), stroke.fill,
+            ),
+            Primitive::Text { pos, text, height, col } => write!(
+                f,
+                "text pos=({}, {}) height={} col={} {:?}",
+                Coord(pos.x), Coord(pos.y), Coord(*height), col, text,
+            ),
+            Primitive::PushClip { rect } => write!(
+                f,
+                "push_clip pos=({}, {}) size=({}, {})",
+                Coord(rect.x), Coord(rect.y), Coord(rect.w), Coord(rect.h),
+            ),
+            Primitive::PopClip => write!(f, "pop_clip"),
+        }
+    }
 }
 
 #[derive(Default)]
@@ -16,6 +67,26 @@ pub struct Canvas {
 }
 
 impl Canvas {
+    pub fn primitives(&self) -> &[Primitive] {
+        &self.primitives
+    }
+
+    /// A textual dump of the primitives drawn to this canvas, one per line.
+    pub fn dump(&self) -> String {
+        self.primitives
+            .iter()
+            .map(|prim| format!("{}\n", prim))
+            .collect()
+    }
+
+    /// Draw with everything confined to the given bounds.
+    pub fn clipped<R>(&mut self, bounds: Bounds, f: impl FnOnce(&mut Self) -> R) -> R {
+        self.primitives.push(Primitive::PushClip { rect: bounds.rect });
+        let r = f(self);
+        self.primitives.push(Primitive::PopClip);
+        r
+    }
+
     pub fn bounded<'a>(&'a mut self, bounds: Bounds) -> BoundedCanvas<'a> {
         BoundedCanvas {
             aabr: Aabr {
@@ -35,6 +106,15 @@ pub struct BoundedCanvas<'a> {
 impl<'a> BoundedCanvas<'a> {
     pub fn size(&self) -> [f32; 2] { self.aabr.size().into_array() }
 
+    /// Draw with everything confined to a region, relative to these bounds.
+    pub fn clipped<R>(&mut self, pos: [f32; 2], size: [f32; 2], f: impl FnOnce(&mut BoundedCanvas) -> R) -> R {
+        let aabr = self.aabr;
+        self.canvas.clipped(
+            Bounds::new((aabr.min + Vec2::from(pos)).into_array(), size),
+            |canvas| f(&mut BoundedCanvas { aabr, canvas }),
+        )
+    }
+
     pub fn bounds(&self) -> Bounds {
         Bounds { rect: self.aabr.into() }
     }
@@ -59,6 +139,10 @@ impl<'a> BoundedCanvas<'a> {
     }
 
     pub fn draw_rect(&mut self, pos: [f32; 2], size: [f32; 2], fill: impl Into<Fill>) {
+        self.draw_rounded_rect(pos, size, 0.0, fill)
+    }
+
+    pub fn draw_rounded_rect(&mut self, pos: [f32; 2], size: [f32; 2], radius: f32, fill: impl Into<Fill>) {
         self.canvas.primitives.push(Primitive::Rect {
             rect: Rect::new(
                 self.aabr.min.x + pos[0],
@@ -67,10 +151,15 @@ impl<'a> BoundedCanvas<'a> {
                 size[1],
             ),
             fill: fill.into(),
+            radius,
         });
     }
 
     pub fn fill(&mut self, fill: impl Into<Fill>) {
         self.draw_rect([0.0; 2], self.size(), fill)
     }
+
+    pub fn fill_rounded(&mut self, radius: f32, fill: impl Into<Fill>) {
+        self.draw_rounded_rect([0.0; 2], self.size(), radius, fill)
+    }
 }
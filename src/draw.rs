@@ -1,3 +1,6 @@
+use std::fmt;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Color {
     pub r: u8,
     pub g: u8,
@@ -16,6 +19,18 @@ impl Color {
     pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
         Self { r, g, b, a }
     }
+
+    /// This colour moved towards `other` by the fraction `t`, from 0 (this colour) to 1 (`other`).
+    pub fn mix(self, other: Self, t: f32) -> Self {
+        let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        Self::new(lerp(self.r, other.r), lerp(self.g, other.g), lerp(self.b, other.b), lerp(self.a, other.a))
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}{:02x}", self.r, self.g, self.b, self.a)
+    }
 }
 
 impl From<Color> for [u8; 4] {
@@ -24,16 +39,26 @@ impl From<Color> for [u8; 4] {
     }
 }
 
+#[derive(Clone, Debug, PartialEq)]
 pub enum Fill {
     Color(Color),
 }
 
+impl fmt::Display for Fill {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Fill::Color(col) => write!(f, "{}", col),
+        }
+    }
+}
+
 impl From<Color> for Fill {
     fn from(color: Color) -> Self {
         Fill::Color(color)
     }
 }
 
+#[derive(Clone, Debug, PartialEq)]
 pub struct Stroke {
     pub width: f32,
     pub fill: Fill,
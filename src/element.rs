@@ -1,15 +1,22 @@
 use crate::{
-    canvas::Canvas,
+    canvas::{Canvas, Primitive},
     layout::{Bounds, LayoutReq},
-    Widget, Event, Response,
+    Env, Widget, Event, MouseButton, Response,
 };
-use vek::*;
-use std::ops::{Deref, DerefMut};
+use std::mem;
 
 pub struct Element<'a, D> {
     widget: Box<dyn Widget<'a, D> + 'a>,
     bounds: Bounds,
     last_layout: LayoutReq,
+    /// The mouse button held down when this element, or one of its descendants, captured the pointer.
+    captured: Option<MouseButton>,
+    /// Whether this element, or one of its descendants, may lay out or draw differently than it last did.
+    dirty: bool,
+    /// The environment the element was last laid out and drawn in.
+    env: Option<Env>,
+    /// The primitives the element last drew, to be reused for as long as it isn't dirty.
+    drawn: Vec<Primitive>,
 }
 
 impl<'a, D> Element<'a, D> {
@@ -18,6 +25,10 @@ impl<'a, D> Element<'a, D> {
             widget: Box::new(widget),
             bounds: Bounds::global([0.0; 2]),
             last_layout: LayoutReq::any(),
+            captured: None,
+            dirty: true,
+            env: None,
+            drawn: Vec::new(),
         }
     }
 
@@ -25,31 +36,131 @@ impl<'a, D> Element<'a, D> {
         self.last_layout
     }
 
-    pub(crate) fn get_layout_req(&mut self) -> LayoutReq {
-        let layout = self.widget.get_layout_req();
-        self.last_layout = layout;
-        layout
+    pub fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
+    pub fn find_bounds(&mut self, id: &str) -> Option<Bounds> {
+        self.widget.find_bounds(id)
+    }
+
+    /// Bring the element up to date after the data may have changed, returning whether it is now dirty.
+    pub(crate) fn update(&mut self, data: &mut D) -> bool {
+        self.dirty |= self.widget.update(data);
+        self.dirty
+    }
+
+    /// Mark the element as dirty if the environment, such as its theme, has changed since it was last laid out or
+    /// drawn.
+    fn check_env(&mut self, env: &Env) {
+        if !self.env.as_ref().is_some_and(|old| old.same_as(env)) {
+            self.dirty = true;
+            self.env = Some(env.clone());
+        }
+    }
+
+    pub(crate) fn get_layout_req(&mut self, data: &mut D, env: &Env) -> LayoutReq {
+        self.check_env(env);
+        if self.dirty {
+            self.last_layout = self.widget.get_layout_req(data, env);
+        }
+        self.last_layout
     }
 
     pub(crate) fn fit_bounds(&mut self, bounds: Bounds) {
-        self.bounds = bounds;
-        self.widget.fit_bounds(bounds);
+        if self.dirty || bounds != self.bounds {
+            self.dirty = true;
+            self.bounds = bounds;
+            self.widget.fit_bounds(bounds);
+        }
+    }
+
+    /// Handle an event as the root of a widget tree.
+    pub(crate) fn dispatch(&mut self, data: &mut D, env: &Env, event: &Event) -> Response {
+        let mut resp = Response {
+            in_capture: self.captured.is_some(),
+            ..Response::default()
+        };
+        self.handle(data, env, event, &mut resp);
+        resp
     }
 
     pub(crate) fn handle(
         &mut self,
         data: &mut D,
+        env: &Env,
         event: &Event,
         resp: &mut Response,
     ) -> bool {
-        self.widget.handle(data, event, self.bounds, resp)
+        // While the pointer is captured, pointer events only travel down the path to the capturing widget. Clicks are
+        // the exception, so that every other widget still gets the chance to e.g. lose focus.
+        if resp.in_capture && self.captured.is_none() && event.is_pointer() && !matches!(event, Event::Click(..)) {
+            return false;
+        }
+
+        let outer_capture = mem::take(&mut resp.capture);
+        let outer_redraw = mem::take(&mut resp.redraw);
+        let handled = self.widget.handle(data, env, event, self.bounds, resp);
+        if resp.capture && self.captured.is_none() {
+            self.captured = match event {
+                Event::MouseDown(_, button) | Event::Drag { button, .. } => Some(*button),
+                _ => None,
+            };
+        }
+        self.dirty |= resp.redraw;
+        resp.capture |= outer_capture;
+        resp.redraw |= outer_redraw;
+
+        // Only releasing the button that started the capture ends it
+        if let Event::MouseUp(_, button) = event {
+            if self.captured == Some(*button) {
+                self.captured = None;
+            }
+        }
+
+        handled
+    }
+
+    /// Offer an event to each of `children` in turn until one of them consumes it. Those after it still see a
+    /// consumed click, but at a position outside of every widget.
+    pub(crate) fn handle_in_turn<'e>(
+        children: impl Iterator<Item=&'e mut Self>,
+        data: &mut D,
+        env: &Env,
+        event: &Event,
+        resp: &mut Response,
+    ) -> bool where 'a: 'e, D: 'e {
+        let mut consumed = None;
+        for child in children {
+            match &consumed {
+                None => if child.handle(data, env, event, resp) {
+                    match event.missed() {
+                        Some(missed) => consumed = Some(missed),
+                        None => return true,
+                    }
+                },
+                Some(missed) => { child.handle(data, env, missed, resp); },
+            }
+        }
+        consumed.is_some()
     }
 
     pub(crate) fn draw(
         &mut self,
         data: &mut D,
+        env: &Env,
         canvas: &mut Canvas,
     ) {
-        self.widget.draw(data, self.bounds, canvas)
+        self.check_env(env);
+        if !self.dirty {
+            canvas.primitives.extend_from_slice(&self.drawn);
+            return;
+        }
+
+        let start = canvas.primitives.len();
+        let (widget, bounds) = (&mut self.widget, self.bounds);
+        canvas.clipped(bounds, |canvas| widget.draw(data, env, bounds, canvas));
+        self.drawn = canvas.primitives[start..].to_vec();
+        self.dirty = false;
     }
 }
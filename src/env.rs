@@ -0,0 +1,56 @@
+use crate::{
+    text::{TextMetrics, FixedMetrics},
+    theme::Theme,
+};
+use std::rc::Rc;
+
+/// What widgets need to know about their surroundings to lay themselves out, handle events and draw, passed down the
+/// widget tree alongside the data.
+#[derive(Clone)]
+pub struct Env {
+    metrics: Rc<dyn TextMetrics>,
+    theme: Rc<Theme>,
+}
+
+impl Env {
+    /// An environment using the given metrics and the default theme.
+    pub fn new(metrics: Rc<dyn TextMetrics>) -> Self {
+        Self {
+            metrics,
+            theme: Rc::new(Theme::default()),
+        }
+    }
+
+    pub fn with_metrics(mut self, metrics: Rc<dyn TextMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    pub fn with_theme(mut self, theme: impl Into<Rc<Theme>>) -> Self {
+        self.theme = theme.into();
+        self
+    }
+
+    /// The metrics that text is laid out with, matching how the backend draws it.
+    pub fn metrics(&self) -> &dyn TextMetrics {
+        &*self.metrics
+    }
+
+    /// The theme that widgets lay themselves out and draw with.
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Whether widgets would lay out and draw the same in either environment.
+    pub(crate) fn same_as(&self, other: &Env) -> bool {
+        Rc::ptr_eq(&self.metrics, &other.metrics)
+            && (Rc::ptr_eq(&self.theme, &other.theme) || self.theme == other.theme)
+    }
+}
+
+impl Default for Env {
+    /// An environment with [`FixedMetrics`], for when no real fonts are available, and the default theme.
+    fn default() -> Self {
+        Self::new(Rc::new(FixedMetrics))
+    }
+}
@@ -1 +1,7 @@
+use super::EventKind;
+
 pub struct Click;
+
+impl EventKind for Click {
+    type Payload = Self;
+}
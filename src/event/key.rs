@@ -0,0 +1,70 @@
+use crate::{
+    keyboard::{Key, Modifiers},
+    Event, Response,
+};
+use super::{EventCtx, EventKind, Handle};
+
+/// Selects the handler run when a key is pressed and not consumed by any widget beneath the one it's attached to.
+pub struct KeyPress;
+
+/// Selects the handler run when a key is released and not consumed by any widget beneath the one it's attached to.
+pub struct KeyRelease;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct KeyPressed {
+    pub key: Key,
+    pub modifiers: Modifiers,
+    /// Whether this press was generated by the key being held down.
+    pub repeat: bool,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct KeyReleased {
+    pub key: Key,
+    pub modifiers: Modifiers,
+}
+
+impl EventKind for KeyPress {
+    type Payload = KeyPressed;
+}
+
+impl EventKind for KeyRelease {
+    type Payload = KeyReleased;
+}
+
+/// The key handlers attached to a widget.
+pub(crate) struct KeyHandlers<'a, D, W> {
+    pub(crate) press: Handle<'a, D, KeyPress, W>,
+    pub(crate) release: Handle<'a, D, KeyRelease, W>,
+}
+
+impl<'a, D, W> Default for KeyHandlers<'a, D, W> {
+    fn default() -> Self {
+        Self { press: None, release: None }
+    }
+}
+
+impl<'a, D, W> KeyHandlers<'a, D, W> {
+    /// Run the handler for a key event, if there is one, returning whether it was handled.
+    pub(crate) fn handle(&mut self, data: &mut D, event: &Event, resp: &mut Response) -> bool {
+        let handled = match event {
+            Event::KeyPress(key) => self.press.as_mut().map(|f| f(EventCtx {
+                widget: std::marker::PhantomData,
+                event: key,
+                data,
+            })),
+            Event::KeyRelease(key) => self.release.as_mut().map(|f| f(EventCtx {
+                widget: std::marker::PhantomData,
+                event: key,
+                data,
+            })),
+            _ => None,
+        }.is_some();
+
+        if handled {
+            resp.mark_changed();
+            resp.redraw();
+        }
+        handled
+    }
+}
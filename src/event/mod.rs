@@ -1,22 +1,37 @@
 pub mod click;
+pub mod key;
+pub mod submit;
 
 pub use self::{
     click::Click,
+    key::{KeyPress, KeyPressed, KeyRelease, KeyReleased},
+    submit::Submit,
 };
 
-pub trait Handler<'a, D, E> {
+pub(crate) use self::key::KeyHandlers;
+
+/// A kind of event that handlers can be attached to with [`Widget::on`](crate::Widget::on), selected by the type's
+/// value.
+pub trait EventKind {
+    /// What handlers are told about each occurrence of the event.
+    type Payload;
+}
+
+pub trait Handler<'a, D, E: EventKind> {
     fn attach(&mut self, f: impl FnMut(EventCtx<D, E, Self>) + 'a);
 }
 
-pub struct EventCtx<'a, D, E, W> {
+pub struct EventCtx<'a, D, E: EventKind, W> {
     pub widget: std::marker::PhantomData<(E, W)>,//&'a mut W,
+    pub event: &'a E::Payload,
     pub data: &'a mut D,
 }
 
-impl<'a, D, E, W> EventCtx<'a, D, E, W> {
+impl<'a, D, E: EventKind, W> EventCtx<'a, D, E, W> {
     pub fn map<U>(self) -> EventCtx<'a, D, E, U> {
         EventCtx {
             widget: std::marker::PhantomData,
+            event: self.event,
             data: self.data,
         }
     }
@@ -0,0 +1,7 @@
+use super::EventKind;
+
+pub struct Submit;
+
+impl EventKind for Submit {
+    type Payload = Self;
+}
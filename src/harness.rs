@@ -0,0 +1,189 @@
+use crate::{
+    layout::Bounds,
+    text::{TextMetrics, FixedMetrics},
+    theme::Theme,
+    canvas::Canvas,
+    event::{KeyPressed, KeyReleased},
+    Env, Widget, Element, Event, MouseButton, Response, Key, Modifiers,
+};
+use std::rc::Rc;
+
+/// Drives a widget tree with scripted events, for testing widgets without a real window.
+///
+/// Text is measured with [`FixedMetrics`] so that layouts don't depend on the fonts installed, unless other metrics are
+/// given with [`Harness::set_metrics`], and widgets use the default theme unless given another with
+/// [`Harness::set_theme`].
+///
+/// ```ignore
+/// let mut h = Harness::new(ui, [320.0, 240.0], Data::default());
+/// assert!(h.click_on("increment").needs_redraw());
+/// assert_eq!(h.data().counter, 1);
+/// ```
+pub struct Harness<'a, D> {
+    element: Element<'a, D>,
+    data: D,
+    size: [f32; 2],
+    env: Env,
+    /// Whether the data may have changed since the widgets last looked at it.
+    changed: bool,
+}
+
+impl<'a, D> Harness<'a, D> {
+    pub fn new(root: impl Widget<'a, D> + 'a, size: [f32; 2], data: D) -> Self {
+        let mut this = Self {
+            element: root.finish(),
+            data,
+            size,
+            env: Env::new(Rc::new(FixedMetrics)),
+            changed: true,
+        };
+        this.relayout();
+        this
+    }
+
+    pub fn data(&self) -> &D {
+        &self.data
+    }
+
+    pub fn data_mut(&mut self) -> &mut D {
+        self.changed = true;
+        &mut self.data
+    }
+
+    pub fn into_data(self) -> D {
+        self.data
+    }
+
+    pub fn element(&mut self) -> &mut Element<'a, D> {
+        &mut self.element
+    }
+
+    /// Bring the widgets up to date with the data if it may have changed, returning whether that changed them.
+    fn update(&mut self) -> bool {
+        std::mem::take(&mut self.changed) && self.element.update(&mut self.data)
+    }
+
+    /// Bring the widgets up to date and lay them out again if that changed them, as a window does before each event
+    /// and frame.
+    fn refresh(&mut self) {
+        if self.update() {
+            self.layout();
+        }
+    }
+
+    fn layout(&mut self) {
+        self.element.get_layout_req(&mut self.data, &self.env);
+        self.element.fit_bounds(Bounds::global(self.size));
+    }
+
+    pub fn resize(&mut self, size: [f32; 2]) {
+        self.size = size;
+        self.relayout();
+    }
+
+    /// Measure text with the given metrics rather than [`FixedMetrics`].
+    pub fn set_metrics(&mut self, metrics: Rc<dyn TextMetrics>) {
+        self.env = self.env.clone().with_metrics(metrics);
+        self.relayout();
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.env = self.env.clone().with_theme(theme);
+        self.relayout();
+    }
+
+    pub fn relayout(&mut self) {
+        self.update();
+        self.layout();
+    }
+
+    /// The bounds of the widget created with [`Widget::identified`] using the given id.
+    pub fn bounds_of(&mut self, id: &str) -> Option<Bounds> {
+        self.refresh();
+        self.element.find_bounds(id)
+    }
+
+    /// The centre of the widget with the given id. Panics if no such widget exists.
+    pub fn center_of(&mut self, id: &str) -> [f32; 2] {
+        let bounds = self.bounds_of(id)
+            .unwrap_or_else(|| panic!("no widget with id '{}'", id));
+        let ([x, y], [w, h]) = (bounds.pos(), bounds.size());
+        [x + w / 2.0, y + h / 2.0]
+    }
+
+    pub fn inject(&mut self, event: Event) -> Response {
+        self.refresh();
+        let resp = self.element.dispatch(&mut self.data, &self.env, &event);
+        self.changed |= resp.data_changed();
+        resp
+    }
+
+    pub fn cursor_move(&mut self, pos: [f32; 2]) -> Response {
+        self.inject(Event::CursorMove(pos))
+    }
+
+    pub fn click(&mut self, pos: [f32; 2], button: MouseButton) -> Response {
+        self.inject(Event::Click(pos, button))
+    }
+
+    pub fn mouse_down(&mut self, pos: [f32; 2], button: MouseButton) -> Response {
+        self.inject(Event::MouseDown(pos, button))
+    }
+
+    pub fn mouse_up(&mut self, pos: [f32; 2], button: MouseButton) -> Response {
+        self.inject(Event::MouseUp(pos, button))
+    }
+
+    /// Turn the scroll wheel by the given number of notches, with positive values being up and to the left.
+    pub fn scroll(&mut self, pos: [f32; 2], delta: [f32; 2]) -> Response {
+        self.inject(Event::Scroll(pos, delta))
+    }
+
+    /// Press a mouse button at `from`, move to `to` while holding it, then release it.
+    pub fn drag(&mut self, from: [f32; 2], to: [f32; 2], button: MouseButton) -> Response {
+        let mut resp = self.cursor_move(from);
+        resp.merge(self.mouse_down(from, button));
+        resp.merge(self.cursor_move(to));
+        resp.merge(self.inject(Event::Drag {
+            pos: to,
+            delta: [to[0] - from[0], to[1] - from[1]],
+            button,
+        }));
+        resp.merge(self.mouse_up(to, button));
+        resp
+    }
+
+    /// Press and then release a key.
+    pub fn type_key(&mut self, key: Key, modifiers: Modifiers) -> Response {
+        let mut resp = self.inject(Event::KeyPress(KeyPressed { key, modifiers, repeat: false }));
+        resp.merge(self.inject(Event::KeyRelease(KeyReleased { key, modifiers })));
+        resp
+    }
+
+    pub fn type_text(&mut self, text: impl Into<String>) -> Response {
+        self.inject(Event::Text(text.into()))
+    }
+
+    pub fn hover_on(&mut self, id: &str) -> Response {
+        let pos = self.center_of(id);
+        self.cursor_move(pos)
+    }
+
+    /// Move the cursor over the widget with the given id and left-click it, producing the same sequence of events as
+    /// a real mouse would.
+    pub fn click_on(&mut self, id: &str) -> Response {
+        let pos = self.center_of(id);
+        let mut resp = self.cursor_move(pos);
+        resp.merge(self.mouse_down(pos, MouseButton::Left));
+        resp.merge(self.click(pos, MouseButton::Left));
+        resp.merge(self.mouse_up(pos, MouseButton::Left));
+        resp
+    }
+
+    pub fn draw(&mut self) -> Canvas {
+        self.refresh();
+        let mut canvas = Canvas::default();
+        self.element.draw(&mut self.data, &self.env, &mut canvas);
+        canvas
+    }
+}
@@ -7,18 +7,98 @@ pub enum Direction {
     Vertical = 1,
 }
 
-#[derive(Copy, Clone)]
+/// Where children go along the cross axis of a container.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Align {
+    Start,
+    Center,
+    End,
+    /// Fill the available space, up to the child's maximum size.
+    Stretch,
+}
+
+impl Align {
+    /// The offset and size of something with the given span, placed within `avail` space.
+    pub(crate) fn place(self, span: Span, avail: f32) -> (f32, f32) {
+        let size = match self {
+            Align::Stretch => span.max.map_or(avail, |max| max.min(avail)),
+            _ => span.min.min(avail),
+        };
+        let offset = match self {
+            Align::Start | Align::Stretch => 0.0,
+            Align::Center => (avail - size) / 2.0,
+            Align::End => avail - size,
+        };
+        (offset, size)
+    }
+}
+
+/// Where children go along the main axis of a container, when they don't fill it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Justify {
+    Start,
+    Center,
+    End,
+    /// Spread any spare space evenly between the children.
+    SpaceBetween,
+}
+
+/// Space around the sides of something.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Insets {
+    pub left: f32,
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+}
+
+impl Insets {
+    pub fn new(left: f32, top: f32, right: f32, bottom: f32) -> Self {
+        Self { left, top, right, bottom }
+    }
+
+    pub fn uniform(x: f32) -> Self {
+        Self::new(x, x, x, x)
+    }
+
+    /// Insets of `h` on the left and right, and `v` on the top and bottom.
+    pub fn symmetric(h: f32, v: f32) -> Self {
+        Self::new(h, v, h, v)
+    }
+
+    /// The total of the left and right insets.
+    pub fn horizontal(&self) -> f32 {
+        self.left + self.right
+    }
+
+    /// The total of the top and bottom insets.
+    pub fn vertical(&self) -> f32 {
+        self.top + self.bottom
+    }
+}
+
+impl From<f32> for Insets {
+    fn from(x: f32) -> Self {
+        Self::uniform(x)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
 pub struct Bounds {
     pub(crate) rect: Rect<f32, f32>,
 }
 
 impl Bounds {
-    pub fn global(size: [f32; 2]) -> Self {
+    pub fn new(pos: [f32; 2], size: [f32; 2]) -> Self {
         Self {
-            rect: Rect::new(0.0, 0.0, size[0], size[1]),
+            rect: Rect::new(pos[0], pos[1], size[0], size[1]),
         }
     }
 
+    pub fn global(size: [f32; 2]) -> Self {
+        Self::new([0.0; 2], size)
+    }
+
     pub fn pos(&self) -> [f32; 2] {
         self.rect.position().into_array()
     }
@@ -39,13 +119,18 @@ impl Bounds {
         }
     }
 
-    pub fn padded_window(&self, padding: f32) -> Self {
+    /// The window inside the given insets. Insets too big to fit shrink in proportion so that nothing is left over.
+    pub fn padded_window(&self, padding: impl Into<Insets>) -> Self {
+        let padding = padding.into();
+        let fit = |a: f32, b: f32, len: f32| if a + b > len { (a * len / (a + b), b * len / (a + b)) } else { (a, b) };
+        let (left, right) = fit(padding.left, padding.right, self.rect.w);
+        let (top, bottom) = fit(padding.top, padding.bottom, self.rect.h);
         Self {
             rect: Rect::new(
-                self.rect.x + padding.min(self.rect.w / 2.0),
-                self.rect.y + padding.min(self.rect.h / 2.0),
-                self.rect.w - padding.min(self.rect.w / 2.0) * 2.0,
-                self.rect.h - padding.min(self.rect.h / 2.0) * 2.0,
+                self.rect.x + left,
+                self.rect.y + top,
+                self.rect.w - left - right,
+                self.rect.h - top - bottom,
             ),
         }
     }
@@ -58,7 +143,7 @@ impl Bounds {
 #[derive(Copy, Clone, Debug)]
 pub struct Span {
     pub(crate) min: f32,
-    max: Option<f32>,
+    pub(crate) max: Option<f32>,
 }
 
 impl Span {
@@ -96,6 +181,17 @@ impl Span {
             },
         }
     }
+
+    /// A span big enough for either, only limited if both are.
+    pub fn cover(self, other: Self) -> Self {
+        Self {
+            min: self.min.max(other.min),
+            max: match (self.max, other.max) {
+                (Some(s), Some(o)) => Some(s.max(o)),
+                (_, _) => None,
+            },
+        }
+    }
 }
 
 impl std::ops::Add for Span {
@@ -126,13 +222,12 @@ impl LayoutReq {
         Self::new([Span::min(0.0), Span::min(0.0)])
     }
 
-    pub fn padded(self, padding: f32) -> Self {
-        Self {
-            span: self.span.map(|e| Span {
-                min: e.min + padding * 2.0,
-                max: e.max.map(|max| max + padding * 2.0),
-            }),
-        }
+    pub fn padded(self, padding: impl Into<Insets>) -> Self {
+        let padding = padding.into();
+        Self::new([
+            self.span.w + Span::exactly(padding.horizontal()),
+            self.span.h + Span::exactly(padding.vertical()),
+        ])
     }
 
     pub fn width(&self) -> Span {
@@ -151,3 +246,37 @@ impl std::ops::Index<usize> for LayoutReq {
         &self.span[index]
     }
 }
+
+/// Share out `avail` space between spans, giving each at least its minimum and splitting what's left in proportion to
+/// their flex factors, without growing any past its maximum. If the minimums don't fit, they are all shrunk evenly.
+pub(crate) fn distribute(spans: &[Span], flex: &[f32], avail: f32) -> Vec<f32> {
+    let req = spans.iter().map(|s| s.min).sum::<f32>();
+    if req >= avail {
+        let factor = if req > 0.0 { avail.max(0.0) / req } else { 0.0 };
+        return spans.iter().map(|s| s.min * factor).collect();
+    }
+
+    let mut sizes = spans.iter().map(|s| s.min).collect::<Vec<_>>();
+    let mut growing = (0..spans.len()).filter(|i| flex[*i] > 0.0).collect::<Vec<_>>();
+    let mut spare = avail - req;
+    // Hand out the spare space until none is left, or every span that could take some has hit its maximum
+    while spare > 0.0 && !growing.is_empty() {
+        let total_flex = growing.iter().map(|i| flex[*i]).sum::<f32>();
+        let count = growing.len();
+        let mut given = 0.0;
+        growing.retain(|i| {
+            let share = spare * flex[*i] / total_flex;
+            let grown = spans[*i].max.map_or(sizes[*i] + share, |max| (sizes[*i] + share).min(max));
+            given += grown - sizes[*i];
+            let capped = grown < sizes[*i] + share;
+            sizes[*i] = grown;
+            !capped
+        });
+        spare -= given;
+        // Nothing hit its maximum, so all of the spare space was used up
+        if growing.len() == count {
+            break;
+        }
+    }
+    sizes
+}
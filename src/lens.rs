@@ -0,0 +1,57 @@
+use std::marker::PhantomData;
+
+pub use gui_derive::Lens;
+
+/// A projection from a data model `D` onto a part of it, `U`, so that widgets written for `U` can work on a `D`.
+///
+/// Any function taking `&mut D` and returning `&mut U` is a lens, including those generated by `#[derive(Lens)]`.
+pub trait Lens<D, U> {
+    // Requiring the lens to outlive the borrow lets `Then` rely on its intermediate type doing so too
+    fn get_mut<'b>(&mut self, data: &'b mut D) -> &'b mut U where Self: 'b;
+
+    /// Project further, onto a part of `U`.
+    fn then<V, L: Lens<U, V>>(self, other: L) -> Then<Self, L, U> where Self: Sized {
+        Then {
+            first: self,
+            second: other,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<D, U, F: for<'b> FnMut(&'b mut D) -> &'b mut U> Lens<D, U> for F {
+    fn get_mut<'b>(&mut self, data: &'b mut D) -> &'b mut U where Self: 'b {
+        self(data)
+    }
+}
+
+/// A lens made from a closure, such as `Field::new(|d: &mut Data| &mut d.screen)`.
+///
+/// Closures passed straight to something expecting a [`Lens`] can't have their lifetimes inferred, so this gives
+/// them a hand.
+pub struct Field<F>(F);
+
+impl<F> Field<F> {
+    pub fn new<D, U>(f: F) -> Self where F: for<'b> FnMut(&'b mut D) -> &'b mut U {
+        Self(f)
+    }
+}
+
+impl<D, U, F: for<'b> FnMut(&'b mut D) -> &'b mut U> Lens<D, U> for Field<F> {
+    fn get_mut<'b>(&mut self, data: &'b mut D) -> &'b mut U where Self: 'b {
+        (self.0)(data)
+    }
+}
+
+/// Two lenses applied one after the other, created by [`Lens::then`].
+pub struct Then<A, B, U> {
+    first: A,
+    second: B,
+    phantom: PhantomData<fn(U) -> U>,
+}
+
+impl<D, U, V, A: Lens<D, U>, B: Lens<U, V>> Lens<D, V> for Then<A, B, U> {
+    fn get_mut<'b>(&mut self, data: &'b mut D) -> &'b mut V where Self: 'b {
+        self.second.get_mut(self.first.get_mut(data))
+    }
+}
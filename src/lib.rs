@@ -2,28 +2,78 @@ pub mod backend;
 pub mod canvas;
 pub mod draw;
 pub mod element;
+pub mod env;
 pub mod event;
+pub mod harness;
+pub mod keyboard;
 pub mod layout;
+pub mod lens;
 pub mod widget;
+pub mod snapshot;
 pub mod state;
+pub mod text;
+pub mod theme;
 
 pub use self::{
     backend::Window,
     element::Element,
+    env::Env,
+    keyboard::{Key, Modifiers},
+    lens::Lens,
     widget::{Widget, StateWidget},
     state::State,
+    theme::{Theme, Style},
 };
 
+#[derive(Default)]
 pub struct Response {
     redraw: bool,
+    changed: bool,
+    capture: bool,
+    in_capture: bool,
 }
 
 impl Response {
+    /// Note that the widget's appearance has changed. Widgets that haven't been marked as changed (whether by this or
+    /// by their data) reuse what they last drew, so this must be called whenever handling an event changes how a
+    /// widget looks.
     pub fn redraw(&mut self) {
         self.redraw = true;
     }
+
+    pub fn needs_redraw(&self) -> bool {
+        self.redraw
+    }
+
+    /// Note that the data may have been modified, such as by an event handler or a widget editing its bound state, so
+    /// that every widget depending on the data gets brought up to date.
+    pub fn mark_changed(&mut self) {
+        self.changed = true;
+    }
+
+    pub fn data_changed(&self) -> bool {
+        self.changed
+    }
+
+    /// Combine with the response to a later event.
+    pub(crate) fn merge(&mut self, other: Response) {
+        self.redraw |= other.redraw;
+        self.changed |= other.changed;
+    }
+
+    /// Capture the pointer while handling a `MouseDown`, so that the widget keeps receiving pointer events (even those
+    /// outside its bounds) and no other widget does, apart from clicks, until that mouse button is released.
+    pub fn capture(&mut self) {
+        self.capture = true;
+    }
+
+    /// Whether the pointer is currently captured by some widget.
+    pub fn is_captured(&self) -> bool {
+        self.in_capture || self.capture
+    }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum MouseButton {
     Left,
     Middle,
@@ -33,4 +83,50 @@ pub enum MouseButton {
 pub enum Event {
     CursorMove([f32; 2]),
     Click([f32; 2], MouseButton),
+    MouseDown([f32; 2], MouseButton),
+    MouseUp([f32; 2], MouseButton),
+    /// The cursor moved while a mouse button was held, sent alongside `CursorMove`.
+    Drag { pos: [f32; 2], delta: [f32; 2], button: MouseButton },
+    /// The scroll wheel moved by the given number of notches, with positive values being up and to the left.
+    Scroll([f32; 2], [f32; 2]),
+    KeyPress(event::KeyPressed),
+    KeyRelease(event::KeyReleased),
+    /// Text committed by the keyboard, after layout and dead-key processing.
+    Text(String),
+}
+
+impl Event {
+    /// Whether this event comes from the pointer, and so should go to the widget that has captured it.
+    pub fn is_pointer(&self) -> bool {
+        matches!(self,
+            Event::CursorMove(_)
+            | Event::Click(..)
+            | Event::MouseDown(..)
+            | Event::MouseUp(..)
+            | Event::Drag { .. }
+            | Event::Scroll(..)
+        )
+    }
+
+    /// The event as seen by widgets that it didn't reach, such as those after the widget that consumed it. Clicks still
+    /// arrive, though nowhere near any widget, so that a focused widget knows to lose focus.
+    pub(crate) fn missed(&self) -> Option<Event> {
+        match self {
+            Event::Click(_, button) => Some(Event::Click([f32::INFINITY; 2], *button)),
+            _ => None,
+        }
+    }
+
+    /// The position of the cursor when this event happened, for pointer events.
+    pub fn pos(&self) -> Option<[f32; 2]> {
+        match self {
+            Event::CursorMove(pos)
+            | Event::Click(pos, _)
+            | Event::MouseDown(pos, _)
+            | Event::MouseUp(pos, _)
+            | Event::Drag { pos, .. }
+            | Event::Scroll(pos, _) => Some(*pos),
+            _ => None,
+        }
+    }
 }
@@ -0,0 +1,72 @@
+use std::{fs, path::Path};
+
+/// Compare `actual` against the snapshot stored at `path`, panicking with a line diff if they differ.
+///
+/// When the `UPDATE_SNAPSHOTS` environment variable is set, the snapshot is written instead. A missing snapshot is a
+/// failure otherwise, so that a deleted snapshot can't silently pass.
+///
+/// ```ignore
+/// let mut h = Harness::new(ui, [320.0, 240.0], Data::default());
+/// assert_snapshot(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots/calc.snap"), &h.draw().dump());
+/// ```
+pub fn assert_snapshot(path: impl AsRef<Path>, actual: &str) {
+    let path = path.as_ref();
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).unwrap();
+        }
+        fs::write(path, actual).unwrap();
+        return;
+    }
+
+    match fs::read_to_string(path) {
+        Ok(expected) => if expected != actual {
+            panic!(
+                "snapshot '{}' does not match (rerun with UPDATE_SNAPSHOTS=1 to accept):\n{}",
+                path.display(),
+                diff(&expected, actual),
+            );
+        },
+        Err(err) => panic!(
+            "snapshot '{}' could not be read ({}), rerun with UPDATE_SNAPSHOTS=1 to create it",
+            path.display(),
+            err,
+        ),
+    }
+}
+
+/// A minimal line-based diff of two strings, with removed lines prefixed by `-` and added lines by `+`.
+pub fn diff(old: &str, new: &str) -> String {
+    let old = old.lines().collect::<Vec<_>>();
+    let new = new.lines().collect::<Vec<_>>();
+
+    // Longest common subsequence lengths of each pair of suffixes
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            out += &format!("  {}\n", old[i]);
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            out += &format!("- {}\n", old[i]);
+            i += 1;
+        } else {
+            out += &format!("+ {}\n", new[j]);
+            j += 1;
+        }
+    }
+    out
+}
@@ -1,18 +1,53 @@
+use crate::lens::Lens;
+
+pub type BindLens<'a, D, S> = Box<dyn Lens<D, S> + 'a>;
+pub type Generator<'a, D, S> = Box<dyn FnMut(&mut D) -> S + 'a>;
+
 pub enum State<'a, D, S> {
-    Bind(Box<dyn for<'b> FnMut(&'b mut D) -> &'b mut S + 'a>),
-    Generate(S, Box<dyn FnMut(&mut D) -> S + 'a>),
+    /// State kept in the data, along with a copy of it from when it was last updated so that changes can be noticed.
+    Bind(BindLens<'a, D, S>, Option<S>),
+    /// A value generated from the data, kept until the widget is next updated after the data may have changed.
+    Generate(S, Generator<'a, D, S>),
     Inner(S),
 }
 
 impl<'a, D, S> State<'a, D, S> {
     pub fn get_mut<'b, 'c: 'b>(&'c mut self, data: &'b mut D) -> &'b mut S {
         match self {
-            State::Bind(f) => f(data),
+            State::Bind(lens, _) => lens.get_mut(data),
+            State::Generate(s, _) => s,
+            State::Inner(s) => s,
+        }
+    }
+
+    /// Generate the state again from data that may have changed, for state that can't be compared to tell whether it
+    /// did.
+    pub fn regenerate(&mut self, data: &mut D) {
+        if let State::Generate(s, f) = self {
+            *s = f(data);
+        }
+    }
+}
+
+impl<'a, D, S: Clone + PartialEq> State<'a, D, S> {
+    /// Check whether the state is different from when this was last called, given data that may have changed.
+    pub fn update(&mut self, data: &mut D) -> bool {
+        match self {
+            State::Bind(lens, last) => {
+                let s = lens.get_mut(data);
+                let changed = last.as_ref() != Some(s);
+                if changed {
+                    *last = Some(s.clone());
+                }
+                changed
+            },
             State::Generate(s, f) => {
-                *s = f(data);
-                s
+                let new = f(data);
+                let changed = new != *s;
+                *s = new;
+                changed
             },
-            State::Inner(s) => s,
+            State::Inner(_) => false,
         }
     }
 }
@@ -0,0 +1,32 @@
+/// A source of text measurements, so that widgets can lay text out the same way the backend will draw it.
+pub trait TextMetrics {
+    /// The size of a single line of text drawn at the given height.
+    fn measure(&self, text: &str, height: f32) -> [f32; 2];
+
+    /// The horizontal advance of a run of text.
+    fn width_of(&self, text: &str, height: f32) -> f32 {
+        self.measure(text, height)[0]
+    }
+
+    /// The position of the caret before each character of a single line of text, followed by that after the last.
+    ///
+    /// By default every character is measured on its own, which ignores kerning between them.
+    fn carets(&self, text: &str, height: f32) -> Vec<f32> {
+        let mut x = 0.0;
+        std::iter::once(0.0)
+            .chain(text.chars().map(|c| {
+                x += self.width_of(c.encode_utf8(&mut [0; 4]), height);
+                x
+            }))
+            .collect()
+    }
+}
+
+/// Metrics that give every character the same advance, for when no real fonts are available.
+pub struct FixedMetrics;
+
+impl TextMetrics for FixedMetrics {
+    fn measure(&self, text: &str, height: f32) -> [f32; 2] {
+        [text.chars().count() as f32 * height * 0.45, height]
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{
+    draw::Color,
+    canvas::BoundedCanvas,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Palette {
+    /// Behind everything, where no widget draws.
+    pub background: Color,
+    /// The resting fill of buttons, toggles and text fields.
+    pub surface: Color,
+    pub hover: Color,
+    pub pressed: Color,
+    /// The fill of toggles that are switched on.
+    pub accent: Color,
+    pub border: Color,
+    pub text: Color,
+    pub selection: Color,
+    pub track: Color,
+    pub thumb: Color,
+    pub thumb_hover: Color,
+    pub thumb_active: Color,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Theme {
+    pub palette: Palette,
+    /// The line height of text.
+    pub text_height: f32,
+    /// The space between the edge of a text field and its text.
+    pub padding: f32,
+    /// The corner radius of buttons, toggles and text fields.
+    pub radius: f32,
+    /// The width of the border around buttons, toggles and text fields.
+    pub border_width: f32,
+}
+
+impl Theme {
+    pub fn light() -> Self {
+        Self {
+            palette: Palette {
+                background: Color::new(0x80, 0x80, 0x80, 0xFF),
+                surface: Color::WHITE,
+                hover: Color::new(0xD0, 0xD0, 0xD0, 0xFF),
+                pressed: Color::new(0xA0, 0xA0, 0xA0, 0xFF),
+                accent: Color::new(0x40, 0x80, 0xFF, 0xFF),
+                border: Color::new(0x60, 0x60, 0x60, 0xFF),
+                text: Color::BLACK,
+                selection: Color::new(0x99, 0xC8, 0xFF, 0xFF),
+                track: Color::new(0xE0, 0xE0, 0xE0, 0xFF),
+                thumb: Color::new(0xA0, 0xA0, 0xA0, 0xFF),
+                thumb_hover: Color::new(0x80, 0x80, 0x80, 0xFF),
+                thumb_active: Color::new(0x60, 0x60, 0x60, 0xFF),
+            },
+            text_height: 20.0,
+            padding: 4.0,
+            radius: 0.0,
+            border_width: 0.0,
+        }
+    }
+
+    pub fn dark() -> Self {
+        Self {
+            palette: Palette {
+                background: Color::new(0x20, 0x20, 0x20, 0xFF),
+                surface: Color::new(0x38, 0x38, 0x38, 0xFF),
+                hover: Color::new(0x48, 0x48, 0x48, 0xFF),
+                pressed: Color::new(0x58, 0x58, 0x58, 0xFF),
+                accent: Color::new(0x30, 0x68, 0xD0, 0xFF),
+                border: Color::new(0x58, 0x58, 0x58, 0xFF),
+                text: Color::new(0xE8, 0xE8, 0xE8, 0xFF),
+                selection: Color::new(0x30, 0x50, 0x80, 0xFF),
+                track: Color::new(0x30, 0x30, 0x30, 0xFF),
+                thumb: Color::new(0x60, 0x60, 0x60, 0xFF),
+                thumb_hover: Color::new(0x78, 0x78, 0x78, 0xFF),
+                thumb_active: Color::new(0x90, 0x90, 0x90, 0xFF),
+            },
+            text_height: 20.0,
+            padding: 4.0,
+            radius: 4.0,
+            border_width: 1.0,
+        }
+    }
+
+    /// Fill the canvas as the frame of a button, toggle or text field.
+    pub fn draw_frame(&self, canvas: &mut BoundedCanvas, fill: Color) {
+        let [w, h] = canvas.size();
+        let border = self.border_width.min(w / 2.0).min(h / 2.0);
+        if border > 0.0 {
+            canvas.fill_rounded(self.radius, self.palette.border);
+        }
+        canvas.draw_rounded_rect(
+            [border; 2],
+            [w - border * 2.0, h - border * 2.0],
+            (self.radius - border).max(0.0),
+            fill,
+        );
+    }
+}
+
+/// Overrides for parts of a theme, applied to a subtree with [`Widget::styled`](crate::Widget::styled).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Style {
+    /// The resting fill of buttons, toggles and text fields, from which those of hovered and pressed ones are derived.
+    pub background: Option<Color>,
+    pub foreground: Option<Color>,
+    pub border: Option<(f32, Color)>,
+    pub radius: Option<f32>,
+    pub text_height: Option<f32>,
+}
+
+impl Style {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    pub fn foreground(mut self, color: Color) -> Self {
+        self.foreground = Some(color);
+        self
+    }
+
+    pub fn border(mut self, width: f32, color: Color) -> Self {
+        self.border = Some((width, color));
+        self
+    }
+
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.radius = Some(radius);
+        self
+    }
+
+    pub fn text_height(mut self, height: f32) -> Self {
+        self.text_height = Some(height);
+        self
+    }
+
+    /// The theme with these overrides applied to it.
+    pub fn apply(&self, theme: &Theme) -> Theme {
+        let mut theme = theme.clone();
+        if let Some(color) = self.foreground {
+            theme.palette.text = color;
+        }
+        // Widgets hovered or pressed darken or lighten towards the text colour, as the built-in themes do
+        if let Some(color) = self.background {
+            theme.palette.surface = color;
+            theme.palette.hover = color.mix(theme.palette.text, 0.15);
+            theme.palette.pressed = color.mix(theme.palette.text, 0.3);
+        }
+        if let Some((width, color)) = self.border {
+            theme.border_width = width;
+            theme.palette.border = color;
+        }
+        if let Some(radius) = self.radius {
+            theme.radius = radius;
+        }
+        if let Some(height) = self.text_height {
+            theme.text_height = height;
+        }
+        theme
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{
+    canvas::Canvas,
+    layout::{Align, Bounds, LayoutReq, Span},
+    event::{EventCtx, EventKind, Handler},
+    Env, Widget, Event, Element, Response,
+};
+use std::marker::PhantomData;
+
+/// Gives a widget the size it asks for, placed within whatever space it's offered.
+pub struct Aligned<'a, D, W: Widget<'a, D>> {
+    align: [Align; 2],
+    req: LayoutReq,
+    bounds: Bounds,
+    inner: W,
+    phantom: PhantomData<&'a D>,
+}
+
+impl<'a, D, W: Widget<'a, D>> Aligned<'a, D, W> {
+    pub fn new(inner: W, h: Align, v: Align) -> Self {
+        Self {
+            align: [h, v],
+            req: LayoutReq::any(),
+            bounds: Bounds::global([0.0; 2]),
+            inner,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, D, W: Widget<'a, D>> Widget<'a, D> for Aligned<'a, D, W> {
+    fn children(&mut self) -> Box<dyn Iterator<Item=&mut Element<'a, D>> + '_> {
+        self.inner.children()
+    }
+
+    fn update(&mut self, data: &mut D) -> bool {
+        self.inner.update(data)
+    }
+
+    fn find_bounds(&mut self, id: &str) -> Option<Bounds> {
+        self.inner.find_bounds(id)
+    }
+
+    fn handle(
+        &mut self,
+        data: &mut D,
+        env: &Env,
+        event: &Event,
+        _bounds: Bounds,
+        resp: &mut Response,
+    ) -> bool {
+        self.inner.handle(data, env, event, self.bounds, resp)
+    }
+
+    fn get_layout_req(&mut self, data: &mut D, env: &Env) -> LayoutReq {
+        self.req = self.inner.get_layout_req(data, env);
+        LayoutReq::new([Span::min(self.req[0].min), Span::min(self.req[1].min)])
+    }
+
+    fn fit_bounds(&mut self, bounds: Bounds) {
+        let size = bounds.size();
+        let (x, w) = self.align[0].place(self.req[0], size[0]);
+        let (y, h) = self.align[1].place(self.req[1], size[1]);
+        self.bounds = bounds.window([x, y], [w, h]);
+        self.inner.fit_bounds(self.bounds)
+    }
+
+    fn draw(
+        &mut self,
+        data: &mut D,
+        env: &Env,
+        _bounds: Bounds,
+        canvas: &mut Canvas,
+    ) {
+        self.inner.draw(data, env, self.bounds, canvas)
+    }
+}
+
+impl<'a, D, E: EventKind, W: Widget<'a, D> + Handler<'a, D, E>> Handler<'a, D, E> for Aligned<'a, D, W> {
+    fn attach(&mut self, mut f: impl FnMut(EventCtx<D, E, Self>) + 'a) {
+        self.inner.attach(move |ctx| f(ctx.map()));
+    }
+}
@@ -1,9 +1,8 @@
 use crate::{
-    draw::Color,
     canvas::Canvas,
     layout::{Bounds, LayoutReq},
-    event::{Handle, Handler, Click, EventCtx},
-    Widget, StateWidget, State, Event, Element, Response,
+    event::{Handle, Handler, Click, KeyHandlers, KeyPress, KeyRelease, EventCtx},
+    Env, Widget, StateWidget, State, Event, Element, Response, MouseButton,
 };
 
 pub struct Button<'a, D> {
@@ -11,6 +10,7 @@ pub struct Button<'a, D> {
     is_pressed: bool,
     inner: Option<Element<'a, D>>,
     on_click: Handle<'a, D, Click, Self>,
+    keys: KeyHandlers<'a, D, Self>,
 }
 
 impl<'a, D> Button<'a, D> {
@@ -27,21 +27,27 @@ impl<'a, D> StateWidget<'a, D, ()> for Button<'a, D> {
             is_pressed: false,
             inner: None,
             on_click: None,
+            keys: KeyHandlers::default(),
         }
     }
 }
 
 impl<'a, D> Widget<'a, D> for Button<'a, D> {
+    fn children(&mut self) -> Box<dyn Iterator<Item=&mut Element<'a, D>> + '_> {
+        Box::new(self.inner.as_mut().into_iter())
+    }
+
     fn handle(
         &mut self,
         data: &mut D,
+        env: &Env,
         event: &Event,
         bounds: Bounds,
         resp: &mut Response,
     ) -> bool {
         if self.inner
             .as_mut()
-            .map(|x| x.handle(data, event, resp))
+            .map(|x| x.handle(data, env, event, resp))
             .unwrap_or(false)
         {
             true
@@ -50,13 +56,30 @@ impl<'a, D> Widget<'a, D> for Button<'a, D> {
                 if bounds.contains(*pos) {
                     self.on_click.as_mut().map(|f| f(EventCtx {
                         widget: std::marker::PhantomData,//self,
+                        event: &Click,
                         data,
                     }));
+                    resp.mark_changed();
                     resp.redraw();
                     true
                 } else {
                     false
                 }
+            } else if let Event::MouseDown(pos, MouseButton::Left) = event {
+                if bounds.contains(*pos) {
+                    self.is_pressed = true;
+                    resp.capture();
+                    resp.redraw();
+                    true
+                } else {
+                    false
+                }
+            } else if let Event::MouseUp(_, MouseButton::Left) = event {
+                if self.is_pressed {
+                    self.is_pressed = false;
+                    resp.redraw();
+                }
+                false
             } else if let Event::CursorMove(pos) = event {
                 let old_hover = self.is_hover;
                 self.is_hover = bounds.contains(*pos);
@@ -65,15 +88,15 @@ impl<'a, D> Widget<'a, D> for Button<'a, D> {
                 }
                 false
             } else {
-                false
+                self.keys.handle(data, event, resp)
             }
         }
     }
 
-    fn get_layout_req(&mut self) -> LayoutReq {
+    fn get_layout_req(&mut self, data: &mut D, env: &Env) -> LayoutReq {
         self.inner
             .as_mut()
-            .map(|i| i.get_layout_req())
+            .map(|i| i.get_layout_req(data, env))
             .unwrap_or(LayoutReq::any())
     }
 
@@ -84,19 +107,21 @@ impl<'a, D> Widget<'a, D> for Button<'a, D> {
     fn draw(
         &mut self,
         data: &mut D,
+        env: &Env,
         bounds: Bounds,
         canvas: &mut Canvas,
     ) {
-        canvas.bounded(bounds).fill(if self.is_pressed {
-            Color::RED
+        let theme = env.theme();
+        theme.draw_frame(&mut canvas.bounded(bounds), if self.is_pressed {
+            theme.palette.pressed
         } else if self.is_hover {
-            Color::new(0xD0, 0xD0, 0xD0, 0xFF)
+            theme.palette.hover
         } else {
-            Color::WHITE
+            theme.palette.surface
         });
         self.inner
             .as_mut()
-            .map(|x| x.draw(data, canvas));
+            .map(|x| x.draw(data, env, canvas));
     }
 }
 
@@ -105,3 +130,15 @@ impl<'a, D> Handler<'a, D, Click> for Button<'a, D> {
         self.on_click = Some(Box::new(f));
     }
 }
+
+impl<'a, D> Handler<'a, D, KeyPress> for Button<'a, D> {
+    fn attach(&mut self, f: impl FnMut(EventCtx<D, KeyPress, Self>) + 'a) {
+        self.keys.press = Some(Box::new(f));
+    }
+}
+
+impl<'a, D> Handler<'a, D, KeyRelease> for Button<'a, D> {
+    fn attach(&mut self, f: impl FnMut(EventCtx<D, KeyRelease, Self>) + 'a) {
+        self.keys.release = Some(Box::new(f));
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{
+    canvas::Canvas,
+    layout::{Bounds, LayoutReq, Span},
+    event::{EventCtx, EventKind, Handler},
+    Env, Widget, Event, Element, Response,
+};
+use std::marker::PhantomData;
+
+/// Overrides the size that a widget asks for, and limits the bounds it's given to match.
+pub struct Constrained<'a, D, W: Widget<'a, D>> {
+    min: [Option<f32>; 2],
+    max: [Option<f32>; 2],
+    /// Width divided by height.
+    aspect_ratio: Option<f32>,
+    bounds: Bounds,
+    inner: W,
+    phantom: PhantomData<&'a D>,
+}
+
+impl<'a, D, W: Widget<'a, D>> Constrained<'a, D, W> {
+    pub fn new(inner: W) -> Self {
+        Self {
+            min: [None; 2],
+            max: [None; 2],
+            aspect_ratio: None,
+            bounds: Bounds::global([0.0; 2]),
+            inner,
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn width(mut self, width: f32) -> Self {
+        self.min[0] = Some(width);
+        self.max[0] = Some(width);
+        self
+    }
+
+    pub fn height(mut self, height: f32) -> Self {
+        self.min[1] = Some(height);
+        self.max[1] = Some(height);
+        self
+    }
+
+    pub fn min_size(mut self, size: [f32; 2]) -> Self {
+        self.min = [Some(size[0]), Some(size[1])];
+        self
+    }
+
+    pub fn max_size(mut self, size: [f32; 2]) -> Self {
+        self.max = [Some(size[0]), Some(size[1])];
+        self
+    }
+
+    /// Keep the widget's width divided by its height at `ratio`, which must be positive.
+    pub fn aspect_ratio(mut self, ratio: f32) -> Self {
+        assert!(ratio > 0.0, "aspect ratio must be positive, not {}", ratio);
+        self.aspect_ratio = Some(ratio);
+        self
+    }
+}
+
+impl<'a, D, W: Widget<'a, D>> Widget<'a, D> for Constrained<'a, D, W> {
+    fn children(&mut self) -> Box<dyn Iterator<Item=&mut Element<'a, D>> + '_> {
+        self.inner.children()
+    }
+
+    fn update(&mut self, data: &mut D) -> bool {
+        self.inner.update(data)
+    }
+
+    fn find_bounds(&mut self, id: &str) -> Option<Bounds> {
+        self.inner.find_bounds(id)
+    }
+
+    fn handle(
+        &mut self,
+        data: &mut D,
+        env: &Env,
+        event: &Event,
+        _bounds: Bounds,
+        resp: &mut Response,
+    ) -> bool {
+        self.inner.handle(data, env, event, self.bounds, resp)
+    }
+
+    fn get_layout_req(&mut self, data: &mut D, env: &Env) -> LayoutReq {
+        let req = self.inner.get_layout_req(data, env);
+        let mut min = [0.0; 2];
+        let mut max = [None; 2];
+        for axis in 0..2 {
+            min[axis] = req[axis].min.max(self.min[axis].unwrap_or(0.0));
+            if let Some(limit) = self.max[axis] {
+                min[axis] = min[axis].min(limit);
+            }
+            max[axis] = match (req[axis].max, self.max[axis]) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            };
+        }
+        if let Some(ratio) = self.aspect_ratio {
+            min[0] = min[0].max(min[1] * ratio);
+            min[1] = min[1].max(min[0] / ratio);
+        }
+        LayoutReq::new([
+            Span { min: min[0], max: max[0].map(|max| max.max(min[0])) },
+            Span { min: min[1], max: max[1].map(|max| max.max(min[1])) },
+        ])
+    }
+
+    fn fit_bounds(&mut self, bounds: Bounds) {
+        // The minimum size is part of the layout request, so it's up to the parent to make room for it. Drawing is
+        // clipped to the bounds given, so growing past them would leave part of the widget hidden but still clickable.
+        let mut size = bounds.size();
+        for (axis, size) in size.iter_mut().enumerate() {
+            if let Some(max) = self.max[axis] {
+                *size = size.min(max);
+            }
+        }
+        if let Some(ratio) = self.aspect_ratio {
+            if size[0] > size[1] * ratio {
+                size[0] = size[1] * ratio;
+            } else {
+                size[1] = size[0] / ratio;
+            }
+        }
+        self.bounds = bounds.window([0.0; 2], size);
+        self.inner.fit_bounds(self.bounds)
+    }
+
+    fn draw(
+        &mut self,
+        data: &mut D,
+        env: &Env,
+        _bounds: Bounds,
+        canvas: &mut Canvas,
+    ) {
+        self.inner.draw(data, env, self.bounds, canvas)
+    }
+}
+
+impl<'a, D, E: EventKind, W: Widget<'a, D> + Handler<'a, D, E>> Handler<'a, D, E> for Constrained<'a, D, W> {
+    fn attach(&mut self, mut f: impl FnMut(EventCtx<D, E, Self>) + 'a) {
+        self.inner.attach(move |ctx| f(ctx.map()));
+    }
+}
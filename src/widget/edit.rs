@@ -0,0 +1,207 @@
+use crate::{
+    event::KeyPressed,
+    text::TextMetrics,
+    Key,
+};
+use std::ops::Range;
+
+/// Caret and selection state shared by the text editing widgets.
+///
+/// Positions are byte offsets into the edited string, always on a `char` boundary.
+#[derive(Default)]
+pub(crate) struct Cursor {
+    pub caret: usize,
+    /// The other end of the selection, if there is one.
+    pub anchor: Option<usize>,
+}
+
+impl Cursor {
+    /// Keep the cursor valid if the string was changed from elsewhere.
+    pub fn clamp(&mut self, text: &str) {
+        fn clamp(text: &str, i: usize) -> usize {
+            let mut i = i.min(text.len());
+            while !text.is_char_boundary(i) {
+                i -= 1;
+            }
+            i
+        }
+        self.caret = clamp(text, self.caret);
+        self.anchor = self.anchor.map(|a| clamp(text, a)).filter(|a| *a != self.caret);
+    }
+
+    pub fn selection(&self) -> Option<Range<usize>> {
+        self.anchor.map(|a| a.min(self.caret)..a.max(self.caret))
+    }
+
+    pub fn move_to(&mut self, pos: usize, extend: bool) {
+        if extend {
+            self.anchor = self.anchor.or(Some(self.caret)).filter(|a| *a != pos);
+        } else {
+            self.anchor = None;
+        }
+        self.caret = pos;
+    }
+
+    /// Remove the selected text, returning whether there was any.
+    pub fn delete_selection(&mut self, text: &mut String) -> bool {
+        match self.selection() {
+            Some(range) => {
+                self.caret = range.start;
+                self.anchor = None;
+                text.replace_range(range, "");
+                true
+            },
+            None => false,
+        }
+    }
+
+    pub fn insert(&mut self, text: &mut String, s: &str) {
+        self.delete_selection(text);
+        text.insert_str(self.caret, s);
+        self.caret += s.len();
+    }
+
+    /// Apply the editing keys that behave the same regardless of how text is laid out, returning whether the key was
+    /// used.
+    pub fn handle_key(&mut self, text: &mut String, key: &KeyPressed) -> bool {
+        let (word, extend) = (key.modifiers.ctrl, key.modifiers.shift);
+        match key.key {
+            Key::Left => {
+                let pos = match self.selection() {
+                    Some(range) if !extend => range.start,
+                    _ if word => prev_word(text, self.caret),
+                    _ => prev_char(text, self.caret),
+                };
+                self.move_to(pos, extend);
+            },
+            Key::Right => {
+                let pos = match self.selection() {
+                    Some(range) if !extend => range.end,
+                    _ if word => next_word(text, self.caret),
+                    _ => next_char(text, self.caret),
+                };
+                self.move_to(pos, extend);
+            },
+            Key::Backspace => if !self.delete_selection(text) {
+                let start = if word { prev_word(text, self.caret) } else { prev_char(text, self.caret) };
+                text.replace_range(start..self.caret, "");
+                self.caret = start;
+            },
+            Key::Delete => if !self.delete_selection(text) {
+                let end = if word { next_word(text, self.caret) } else { next_char(text, self.caret) };
+                text.replace_range(self.caret..end, "");
+            },
+            Key::A if key.modifiers.ctrl => {
+                self.anchor = Some(0);
+                self.caret = text.len();
+                self.clamp(text);
+            },
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// The keys whose presses a text widget consumed, so that it only swallows the matching releases.
+#[derive(Default)]
+pub(crate) struct UsedKeys(Vec<Key>);
+
+impl UsedKeys {
+    pub(crate) fn press(&mut self, key: Key) {
+        if !self.0.contains(&key) {
+            self.0.push(key);
+        }
+    }
+
+    /// Forget a released key, returning whether its press was consumed.
+    pub(crate) fn release(&mut self, key: Key) -> bool {
+        let used = self.0.contains(&key);
+        self.0.retain(|k| *k != key);
+        used
+    }
+}
+
+/// The offset of the caret position closest to `x` along a run of text.
+pub(crate) fn offset_at(text: &str, x: f32, metrics: &dyn TextMetrics, height: f32) -> usize {
+    let carets = metrics.carets(text, height);
+    text.char_indices()
+        .zip(carets.windows(2))
+        .find(|(_, lr)| x < (lr[0] + lr[1]) / 2.0)
+        .map_or(text.len(), |((i, _), _)| i)
+}
+
+/// Split text into the byte ranges of its visual lines, breaking at newlines and soft-wrapping (preferably after
+/// whitespace) so that no line is wider than `width`. The ranges do not include the newlines themselves.
+pub(crate) fn wrap(text: &str, width: f32, metrics: &dyn TextMetrics, height: f32) -> Vec<Range<usize>> {
+    let mut lines = Vec::new();
+    let mut hard_start = 0;
+    for hard_line in text.split('\n') {
+        // Each line is measured once, and the widths of its parts found from the caret positions along it
+        let carets = metrics.carets(hard_line, height);
+        let (mut start, mut start_x) = (hard_start, 0.0);
+        let mut last_break = None;
+        for (k, (i, c)) in hard_line.char_indices().enumerate() {
+            let i = hard_start + i;
+            if i > start && carets[k + 1] - start_x > width {
+                let (brk, brk_x) = last_break.filter(|(b, _)| *b > start).unwrap_or((i, carets[k]));
+                lines.push(start..brk);
+                start = brk;
+                start_x = brk_x;
+                last_break = None;
+            }
+            if c.is_whitespace() {
+                last_break = Some((i + c.len_utf8(), carets[k + 1]));
+            }
+        }
+        lines.push(start..hard_start + hard_line.len());
+        hard_start += hard_line.len() + 1;
+    }
+    lines
+}
+
+/// The index of the visual line that the caret at `offset` is displayed on.
+pub(crate) fn line_of(lines: &[Range<usize>], offset: usize) -> usize {
+    lines
+        .iter()
+        .rposition(|line| line.start <= offset)
+        .unwrap_or(0)
+}
+
+pub(crate) fn prev_char(text: &str, i: usize) -> usize {
+    text[..i].char_indices().next_back().map(|(i, _)| i).unwrap_or(0)
+}
+
+pub(crate) fn next_char(text: &str, i: usize) -> usize {
+    text[i..].chars().next().map(|c| i + c.len_utf8()).unwrap_or(i)
+}
+
+/// The start of the word before `i`, skipping any whitespace in between.
+pub(crate) fn prev_word(text: &str, i: usize) -> usize {
+    let mut chars = text[..i].char_indices().rev().peekable();
+    while chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+    let mut start = chars.peek().map(|(i, _)| *i).unwrap_or(0);
+    if let Some((_, first)) = chars.next() {
+        let is_word = is_word_char(first);
+        while let Some((i, _)) = chars.next_if(|(_, c)| !c.is_whitespace() && is_word_char(*c) == is_word) {
+            start = i;
+        }
+    }
+    start
+}
+
+/// The end of the word after `i`, skipping any whitespace in between.
+pub(crate) fn next_word(text: &str, i: usize) -> usize {
+    let mut chars = text[i..].char_indices().map(|(j, c)| (i + j, c)).peekable();
+    while chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+    let mut end = text.len();
+    if let Some((_, first)) = chars.next() {
+        let is_word = is_word_char(first);
+        while chars.next_if(|(_, c)| !c.is_whitespace() && is_word_char(*c) == is_word).is_some() {}
+        end = chars.peek().map(|(i, _)| *i).unwrap_or(text.len());
+    }
+    end
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
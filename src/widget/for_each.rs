@@ -0,0 +1,221 @@
+use crate::{
+    canvas::Canvas,
+    layout::{self, Bounds, Direction, LayoutReq, Span},
+    Env, Widget, StateWidget, State, Event, Element, Response,
+};
+use std::{collections::{HashMap, HashSet}, hash::Hash};
+
+type KeyFn<'a, T, K> = Box<dyn FnMut(usize, &T) -> K + 'a>;
+
+/// Lays out one child per item of a `Vec<T>`, each child working on its own item as its data.
+///
+/// Children are matched to items by key, so that a child keeps its state (such as hover or a text caret) when items
+/// are inserted, removed or moved around it. Without [`ForEach::key`], an item's key is its index. Keys must be unique,
+/// and duplicates cause a panic.
+///
+/// ```ignore
+/// ForEach::<Data, Entry>::bind_state(|d| &mut d.entries)
+///     .key(|entry| entry.id)
+///     .each(|| TextBox::bind_state(|e: &mut Entry| &mut e.text))
+/// ```
+pub struct ForEach<'a, D, T, K = usize> {
+    dir: Direction,
+    spacing: f32,
+    state: State<'a, D, Vec<T>>,
+    template: Option<Box<dyn FnMut() -> Element<'a, T> + 'a>>,
+    key: KeyFn<'a, T, K>,
+    /// The key of the item that each child was last matched to.
+    keys: Vec<K>,
+    /// The child for each item, in the same order as the items.
+    children: Vec<Element<'a, T>>,
+}
+
+impl<'a, D, T, K: Eq + Hash> ForEach<'a, D, T, K> {
+    pub fn horizontal(mut self) -> Self {
+        self.dir = Direction::Horizontal;
+        self
+    }
+
+    pub fn vertical(mut self) -> Self {
+        self.dir = Direction::Vertical;
+        self
+    }
+
+    /// Set the space left between neighbouring children.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Identify items with a key taken from each, rather than by their index. Keys must be unique among the items, and
+    /// a panic follows if they aren't.
+    pub fn key<K2>(self, mut key: impl FnMut(&T) -> K2 + 'a) -> ForEach<'a, D, T, K2> {
+        ForEach {
+            dir: self.dir,
+            spacing: self.spacing,
+            state: self.state,
+            template: self.template,
+            key: Box::new(move |_, item| key(item)),
+            keys: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Set how the child for each item is created.
+    pub fn each<W: Widget<'a, T> + 'a>(mut self, mut template: impl FnMut() -> W + 'a) -> Self {
+        self.template = Some(Box::new(move || template().finish()));
+        self.keys.clear();
+        self.children.clear();
+        self
+    }
+
+    /// Match children up with the items, moving existing children to follow their keys, creating them for new keys
+    /// and dropping those whose keys are gone. Returns whether the children changed.
+    fn sync(&mut self, data: &mut D) -> bool {
+        let key = &mut self.key;
+        let keys = self.state
+            .get_mut(data)
+            .iter()
+            .enumerate()
+            .map(|(i, item)| key(i, item))
+            .collect::<Vec<_>>();
+        if keys == self.keys {
+            return false;
+        }
+        let template = match &mut self.template {
+            Some(template) => template,
+            None => return false,
+        };
+        // Items sharing a key would share a child, leaving the others without one or with another item's state
+        assert_eq!(keys.iter().collect::<HashSet<_>>().len(), keys.len(), "ForEach keys must be unique");
+
+        let mut old = self.keys
+            .drain(..)
+            .zip(self.children.drain(..))
+            .collect::<HashMap<_, _>>();
+        self.children = keys
+            .iter()
+            .map(|key| old.remove(key).unwrap_or_else(&mut *template))
+            .collect();
+        self.keys = keys;
+        true
+    }
+}
+
+impl<'a, D, T> StateWidget<'a, D, Vec<T>> for ForEach<'a, D, T> {
+    fn from_state(state: State<'a, D, Vec<T>>) -> Self {
+        Self {
+            dir: Direction::Vertical,
+            spacing: 0.0,
+            state,
+            template: None,
+            key: Box::new(|i, _| i),
+            keys: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+}
+
+impl<'a, D, T, K: Eq + Hash> Widget<'a, D> for ForEach<'a, D, T, K> {
+    fn find_bounds(&mut self, id: &str) -> Option<Bounds> {
+        self.children
+            .iter_mut()
+            .find_map(|child| child.find_bounds(id))
+    }
+
+    fn update(&mut self, data: &mut D) -> bool {
+        // Items can't be compared, so changes to them are left to their children to notice
+        self.state.regenerate(data);
+        let changed = self.sync(data);
+
+        let items = self.state.get_mut(data);
+        self.children
+            .iter_mut()
+            .zip(items.iter_mut())
+            .fold(changed, |changed, (child, item)| child.update(item) | changed)
+    }
+
+    fn handle(
+        &mut self,
+        data: &mut D,
+        env: &Env,
+        event: &Event,
+        _bounds: Bounds,
+        resp: &mut Response,
+    ) -> bool {
+        // A consumed click still reaches the children after the one that consumed it, as in `Element::handle_in_turn`
+        let mut consumed = None;
+        let items = self.state.get_mut(data);
+        // Children are only matched to items by `update` and `get_layout_req`, which windows and harnesses run whenever
+        // the data changes, so that events go to the children laid out for the items now in the data
+        assert!(
+            self.template.is_none() || self.children.len() == items.len(),
+            "ForEach children must be matched to the items before handling events or drawing",
+        );
+        for (child, item) in self.children.iter_mut().zip(items.iter_mut()) {
+            match &consumed {
+                None => if child.handle(item, env, event, resp) {
+                    match event.missed() {
+                        Some(missed) => consumed = Some(missed),
+                        None => return true,
+                    }
+                },
+                Some(missed) => { child.handle(item, env, missed, resp); },
+            }
+        }
+        consumed.is_some()
+    }
+
+    fn get_layout_req(&mut self, data: &mut D, env: &Env) -> LayoutReq {
+        self.sync(data);
+
+        let axis = self.dir as usize;
+        let mut span = [Span::zero(); 2];
+        span[axis] = Span::exactly(self.spacing * self.children.len().saturating_sub(1) as f32);
+
+        let items = self.state.get_mut(data);
+        for (child, item) in self.children.iter_mut().zip(items.iter_mut()) {
+            let req = child.get_layout_req(item, env);
+            span[axis] = span[axis] + req[axis];
+            span[1 - axis] = span[1 - axis].cover(req[1 - axis]);
+        }
+        LayoutReq::new(span)
+    }
+
+    fn fit_bounds(&mut self, bounds: Bounds) {
+        let axis = self.dir as usize;
+        let size = bounds.size();
+        let avail = size[axis] - self.spacing * self.children.len().saturating_sub(1) as f32;
+
+        // Items take the space they ask for, with nothing stretched to fill the rest
+        let spans = self.children.iter().map(|child| child.last_layout_req()[axis]).collect::<Vec<_>>();
+        let sizes = layout::distribute(&spans, &vec![0.0; spans.len()], avail);
+
+        let mut offset = 0.0;
+        for (child, main) in self.children.iter_mut().zip(sizes) {
+            let (mut pos, mut sz) = ([0.0; 2], size);
+            pos[axis] = offset;
+            sz[axis] = main;
+            child.fit_bounds(bounds.window(pos, sz));
+            offset += main + self.spacing;
+        }
+    }
+
+    fn draw(
+        &mut self,
+        data: &mut D,
+        env: &Env,
+        _bounds: Bounds,
+        canvas: &mut Canvas,
+    ) {
+        let items = self.state.get_mut(data);
+        // As when handling events, the children must already have been matched to the items
+        assert!(
+            self.template.is_none() || self.children.len() == items.len(),
+            "ForEach children must be matched to the items before handling events or drawing",
+        );
+        for (child, item) in self.children.iter_mut().zip(items.iter_mut()) {
+            child.draw(item, env, canvas);
+        }
+    }
+}
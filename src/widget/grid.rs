@@ -0,0 +1,293 @@
+use crate::{
+    canvas::Canvas,
+    layout::{self, Bounds, LayoutReq, Span},
+    event::{Handler, KeyHandlers, KeyPress, KeyRelease, EventCtx},
+    Env, Widget, Event, Element, Response,
+};
+
+/// How the size of a grid row or column is decided.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Track {
+    Fixed(f32),
+    /// Big enough for its contents, and no bigger.
+    Auto,
+    /// A share of the space left over by the other tracks, in proportion to the given factor, but never less than its
+    /// contents need.
+    Fraction(f32),
+}
+
+impl Track {
+    fn span(self, content: f32) -> Span {
+        match self {
+            Track::Fixed(size) => Span::exactly(size),
+            Track::Auto => Span::exactly(content),
+            Track::Fraction(_) => Span::min(content),
+        }
+    }
+
+    fn flex(self) -> f32 {
+        match self {
+            Track::Fraction(f) => f,
+            _ => 0.0,
+        }
+    }
+}
+
+/// The size that a run of tracks needs, given the size of each one's contents.
+fn tracks_span(tracks: &[Track], content: &[f32]) -> Span {
+    let fixed = tracks
+        .iter()
+        .zip(content)
+        .filter(|(t, _)| t.flex() <= 0.0)
+        .fold(Span::zero(), |s, (t, c)| s + t.span(*c));
+    // Fractional tracks grow together, so they need enough room for the one whose contents need the most per fraction
+    let total_flex = tracks.iter().map(|t| t.flex()).filter(|f| *f > 0.0).sum::<f32>();
+    let per_flex = tracks
+        .iter()
+        .zip(content)
+        .filter(|(t, _)| t.flex() > 0.0)
+        .map(|(t, c)| c / t.flex())
+        .fold(0.0, f32::max);
+    if total_flex > 0.0 {
+        fixed + Span::min(per_flex * total_flex)
+    } else {
+        fixed
+    }
+}
+
+/// The size of each track, with the fractional ones splitting whatever space the others leave in proportion to their
+/// fractions. A fractional track whose share is too small for its contents keeps its content size, and the rest
+/// split what's left after it.
+fn track_sizes(tracks: &[Track], content: &[f32], avail: f32) -> Vec<f32> {
+    let spans = tracks.iter().zip(content).map(|(t, c)| t.span(*c)).collect::<Vec<_>>();
+    let flex = tracks.iter().map(|t| t.flex()).collect::<Vec<_>>();
+    if spans.iter().map(|s| s.min).sum::<f32>() >= avail {
+        return layout::distribute(&spans, &flex, avail);
+    }
+
+    let mut sizes = spans.iter().map(|s| s.min).collect::<Vec<_>>();
+    let mut sharing = (0..tracks.len()).filter(|i| flex[*i] > 0.0).collect::<Vec<_>>();
+    loop {
+        let taken = (0..tracks.len())
+            .filter(|i| !sharing.contains(i))
+            .map(|i| sizes[i])
+            .sum::<f32>();
+        let spare = avail - taken;
+        let total_flex = sharing.iter().map(|i| flex[*i]).sum::<f32>();
+        let count = sharing.len();
+        sharing.retain(|i| spare * flex[*i] / total_flex >= sizes[*i]);
+        if sharing.len() == count {
+            for i in sharing {
+                sizes[i] = spare * flex[i] / total_flex;
+            }
+            return sizes;
+        }
+    }
+}
+
+struct Cell {
+    /// The first column and row covered by the cell.
+    pos: [usize; 2],
+    /// The number of columns and rows covered by the cell.
+    span: [usize; 2],
+}
+
+pub struct Grid<'a, D> {
+    /// The column and row tracks. Cells outside of them get extra `Auto` tracks.
+    tracks: [Vec<Track>; 2],
+    gap: [f32; 2],
+    cells: Vec<Cell>,
+    children: Vec<Element<'a, D>>,
+    /// The content size of each column and row, from the last layout request.
+    content: [Vec<f32>; 2],
+    keys: KeyHandlers<'a, D, Self>,
+}
+
+impl<'a, D> Grid<'a, D> {
+    pub fn new() -> Self {
+        Self {
+            tracks: [Vec::new(), Vec::new()],
+            gap: [0.0; 2],
+            cells: Vec::new(),
+            children: Vec::new(),
+            content: [Vec::new(), Vec::new()],
+            keys: KeyHandlers::default(),
+        }
+    }
+
+    pub fn columns(mut self, columns: impl IntoIterator<Item=Track>) -> Self {
+        self.tracks[0] = columns.into_iter().collect();
+        self
+    }
+
+    pub fn rows(mut self, rows: impl IntoIterator<Item=Track>) -> Self {
+        self.tracks[1] = rows.into_iter().collect();
+        self
+    }
+
+    /// Set the space between both columns and rows.
+    pub fn gap(self, gap: f32) -> Self {
+        self.gaps(gap, gap)
+    }
+
+    pub fn gaps(mut self, column_gap: f32, row_gap: f32) -> Self {
+        self.gap = [column_gap, row_gap];
+        self
+    }
+
+    pub fn cell(self, row: usize, column: usize, child: impl Widget<'a, D> + 'a) -> Self {
+        self.span(row, column, 1, 1, child)
+    }
+
+    /// Add a child covering `rows` rows and `columns` columns, starting at the given row and column.
+    pub fn span(
+        mut self,
+        row: usize,
+        column: usize,
+        rows: usize,
+        columns: usize,
+        child: impl Widget<'a, D> + 'a,
+    ) -> Self {
+        self.cells.push(Cell {
+            pos: [column, row],
+            span: [columns.max(1), rows.max(1)],
+        });
+        self.children.push(child.finish());
+        self
+    }
+
+    /// The tracks along an axis, including any implicit ones needed to fit every cell.
+    fn tracks(&self, axis: usize) -> Vec<Track> {
+        let count = self.cells
+            .iter()
+            .map(|cell| cell.pos[axis] + cell.span[axis])
+            .max()
+            .unwrap_or(0)
+            .max(self.tracks[axis].len());
+        let mut tracks = self.tracks[axis].clone();
+        tracks.resize(count, Track::Auto);
+        tracks
+    }
+
+    fn total_gap(&self, axis: usize, tracks: usize) -> f32 {
+        self.gap[axis] * tracks.saturating_sub(1) as f32
+    }
+}
+
+impl<'a, D> Default for Grid<'a, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, D> Widget<'a, D> for Grid<'a, D> {
+    fn children(&mut self) -> Box<dyn Iterator<Item=&mut Element<'a, D>> + '_> {
+        Box::new(self.children.iter_mut())
+    }
+
+    fn handle(
+        &mut self,
+        data: &mut D,
+        env: &Env,
+        event: &Event,
+        _bounds: Bounds,
+        resp: &mut Response,
+    ) -> bool {
+        Element::handle_in_turn(self.children.iter_mut(), data, env, event, resp)
+            || self.keys.handle(data, event, resp)
+    }
+
+    fn get_layout_req(&mut self, data: &mut D, env: &Env) -> LayoutReq {
+        let reqs = self.children
+            .iter_mut()
+            .map(|child| child.get_layout_req(data, env))
+            .collect::<Vec<_>>();
+
+        let mut span = [Span::zero(); 2];
+        for (axis, span) in span.iter_mut().enumerate() {
+            let tracks = self.tracks(axis);
+            let mut content = vec![0.0f32; tracks.len()];
+
+            // Single tracks first, so that spanning cells only grow tracks if they still don't fit
+            for (cell, req) in self.cells.iter().zip(&reqs).filter(|(cell, _)| cell.span[axis] == 1) {
+                let track = &mut content[cell.pos[axis]];
+                *track = track.max(req[axis].min);
+            }
+            for (cell, req) in self.cells.iter().zip(&reqs).filter(|(cell, _)| cell.span[axis] > 1) {
+                let range = cell.pos[axis]..cell.pos[axis] + cell.span[axis];
+                let have = range.clone()
+                    .map(|i| tracks[i].span(content[i]).min)
+                    .sum::<f32>() + self.gap[axis] * (cell.span[axis] - 1) as f32;
+                let growable = range.clone()
+                    .filter(|i| !matches!(tracks[*i], Track::Fixed(_)))
+                    .collect::<Vec<_>>();
+                if req[axis].min > have && !growable.is_empty() {
+                    let extra = (req[axis].min - have) / growable.len() as f32;
+                    growable.into_iter().for_each(|i| content[i] += extra);
+                }
+            }
+
+            *span = Span::exactly(self.total_gap(axis, tracks.len())) + tracks_span(&tracks, &content);
+            self.content[axis] = content;
+        }
+        LayoutReq::new(span)
+    }
+
+    fn fit_bounds(&mut self, bounds: Bounds) {
+        // The start offset and size of each track, along each axis
+        let mut tracks = [Vec::new(), Vec::new()];
+        for (axis, tracks) in tracks.iter_mut().enumerate() {
+            let kinds = self.tracks(axis);
+            let mut content = self.content[axis].clone();
+            content.resize(kinds.len(), 0.0);
+
+            let avail = bounds.size()[axis] - self.total_gap(axis, kinds.len());
+
+            let mut offset = 0.0;
+            *tracks = track_sizes(&kinds, &content, avail)
+                .into_iter()
+                .map(|size| {
+                    let track = (offset, size);
+                    offset += size + self.gap[axis];
+                    track
+                })
+                .collect();
+        }
+
+        for (cell, child) in self.cells.iter().zip(&mut self.children) {
+            let (mut pos, mut size) = ([0.0; 2], [0.0; 2]);
+            for axis in 0..2 {
+                let (first, last) = (cell.pos[axis], cell.pos[axis] + cell.span[axis] - 1);
+                let (start, _) = tracks[axis][first];
+                let (end, last_size) = tracks[axis][last];
+                pos[axis] = start;
+                size[axis] = end + last_size - start;
+            }
+            child.fit_bounds(bounds.window(pos, size));
+        }
+    }
+
+    fn draw(
+        &mut self,
+        data: &mut D,
+        env: &Env,
+        _bounds: Bounds,
+        canvas: &mut Canvas,
+    ) {
+        self.children
+            .iter_mut()
+            .for_each(|child| child.draw(data, env, canvas));
+    }
+}
+
+impl<'a, D> Handler<'a, D, KeyPress> for Grid<'a, D> {
+    fn attach(&mut self, f: impl FnMut(EventCtx<D, KeyPress, Self>) + 'a) {
+        self.keys.press = Some(Box::new(f));
+    }
+}
+
+impl<'a, D> Handler<'a, D, KeyRelease> for Grid<'a, D> {
+    fn attach(&mut self, f: impl FnMut(EventCtx<D, KeyRelease, Self>) + 'a) {
+        self.keys.release = Some(Box::new(f));
+    }
+}
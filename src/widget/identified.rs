@@ -0,0 +1,79 @@
+use crate::{
+    canvas::Canvas,
+    layout::{Bounds, LayoutReq},
+    event::{EventCtx, EventKind, Handler},
+    Env, Widget, Event, Element, Response,
+};
+use std::marker::PhantomData;
+
+pub struct Identified<'a, D, W: Widget<'a, D>> {
+    id: String,
+    bounds: Bounds,
+    inner: W,
+    phantom: PhantomData<&'a D>,
+}
+
+impl<'a, D, W: Widget<'a, D>> Identified<'a, D, W> {
+    pub fn new(inner: W, id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            bounds: Bounds::global([0.0; 2]),
+            inner,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, D, W: Widget<'a, D>> Widget<'a, D> for Identified<'a, D, W> {
+    fn children(&mut self) -> Box<dyn Iterator<Item=&mut Element<'a, D>> + '_> {
+        self.inner.children()
+    }
+
+    fn update(&mut self, data: &mut D) -> bool {
+        self.inner.update(data)
+    }
+
+    fn find_bounds(&mut self, id: &str) -> Option<Bounds> {
+        if self.id == id {
+            Some(self.bounds)
+        } else {
+            self.inner.find_bounds(id)
+        }
+    }
+
+    fn handle(
+        &mut self,
+        data: &mut D,
+        env: &Env,
+        event: &Event,
+        bounds: Bounds,
+        resp: &mut Response,
+    ) -> bool {
+        self.inner.handle(data, env, event, bounds, resp)
+    }
+
+    fn get_layout_req(&mut self, data: &mut D, env: &Env) -> LayoutReq {
+        self.inner.get_layout_req(data, env)
+    }
+
+    fn fit_bounds(&mut self, bounds: Bounds) {
+        self.bounds = bounds;
+        self.inner.fit_bounds(bounds)
+    }
+
+    fn draw(
+        &mut self,
+        data: &mut D,
+        env: &Env,
+        bounds: Bounds,
+        canvas: &mut Canvas,
+    ) {
+        self.inner.draw(data, env, bounds, canvas)
+    }
+}
+
+impl<'a, D, E: EventKind, W: Widget<'a, D> + Handler<'a, D, E>> Handler<'a, D, E> for Identified<'a, D, W> {
+    fn attach(&mut self, mut f: impl FnMut(EventCtx<D, E, Self>) + 'a) {
+        self.inner.attach(move |ctx| f(ctx.map()));
+    }
+}
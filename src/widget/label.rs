@@ -1,8 +1,7 @@
 use crate::{
-    draw::Color,
     canvas::Canvas,
     layout::{Bounds, LayoutReq, Span},
-    Widget, StateWidget, Event, Response, State,
+    Env, Widget, StateWidget, Event, Response, State,
 };
 
 pub struct Label<'a, D> {
@@ -16,19 +15,26 @@ impl<'a, D> StateWidget<'a, D, String> for Label<'a, D> {
 }
 
 impl<'a, D> Widget<'a, D> for Label<'a, D> {
-    fn get_layout_req(&mut self) -> LayoutReq {
+    fn update(&mut self, data: &mut D) -> bool {
+        self.state.update(data)
+    }
+
+    fn get_layout_req(&mut self, data: &mut D, env: &Env) -> LayoutReq {
+        let [w, h] = env.metrics().measure(self.state.get_mut(data), env.theme().text_height);
         LayoutReq::new([
-            Span::exactly(96.0),
-            Span::exactly(32.0),
+            Span::exactly(w),
+            Span::exactly(h),
         ])
     }
 
     fn draw(
         &mut self,
         data: &mut D,
+        env: &Env,
         bounds: Bounds,
         canvas: &mut Canvas,
     ) {
-        canvas.bounded(bounds).draw_text([0.0; 2], self.state.get_mut(data).clone(), 20.0, Color::BLACK);
+        let theme = env.theme();
+        canvas.bounded(bounds).draw_text([0.0; 2], self.state.get_mut(data).clone(), theme.text_height, theme.palette.text);
     }
 }
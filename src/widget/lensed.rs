@@ -0,0 +1,61 @@
+use crate::{
+    canvas::Canvas,
+    layout::{Bounds, LayoutReq},
+    lens::Lens,
+    Env, Widget, Event, Response,
+};
+
+/// Embeds a widget working on `U` in a tree working on `D`, giving it the part of the data that a lens projects onto.
+pub struct Lensed<'a, D, U, W: Widget<'a, U>> {
+    lens: Box<dyn Lens<D, U> + 'a>,
+    inner: W,
+}
+
+impl<'a, D, U, W: Widget<'a, U>> Lensed<'a, D, U, W> {
+    pub fn new(inner: W, lens: impl Lens<D, U> + 'a) -> Self {
+        Self {
+            lens: Box::new(lens),
+            inner,
+        }
+    }
+}
+
+impl<'a, D, U, W: Widget<'a, U>> Widget<'a, D> for Lensed<'a, D, U, W> {
+    // The inner widget's children work on `U`, so they can't be exposed through `children`
+    fn find_bounds(&mut self, id: &str) -> Option<Bounds> {
+        self.inner.find_bounds(id)
+    }
+
+    fn update(&mut self, data: &mut D) -> bool {
+        self.inner.update(self.lens.get_mut(data))
+    }
+
+    fn handle(
+        &mut self,
+        data: &mut D,
+        env: &Env,
+        event: &Event,
+        bounds: Bounds,
+        resp: &mut Response,
+    ) -> bool {
+        self.inner.handle(self.lens.get_mut(data), env, event, bounds, resp)
+    }
+
+    fn get_layout_req(&mut self, data: &mut D, env: &Env) -> LayoutReq {
+        self.inner.get_layout_req(self.lens.get_mut(data), env)
+    }
+
+    fn fit_bounds(&mut self, bounds: Bounds) {
+        self.inner.fit_bounds(bounds)
+    }
+
+    fn draw(
+        &mut self,
+        data: &mut D,
+        env: &Env,
+        bounds: Bounds,
+        canvas: &mut Canvas,
+    ) {
+        self.inner.draw(self.lens.get_mut(data), env, bounds, canvas)
+    }
+}
@@ -1,14 +1,18 @@
 use crate::{
-    draw::Color,
     canvas::Canvas,
-    layout::{Bounds, Direction, LayoutReq, Span},
-    event::{Handle, Handler, Click, EventCtx},
-    Widget, State, Event, Element, Response,
+    layout::{self, Align, Bounds, Direction, Justify, LayoutReq, Span},
+    event::{Handler, KeyHandlers, KeyPress, KeyRelease, EventCtx},
+    Env, Widget, Event, Element, Response,
 };
 
 pub struct List<'a, D> {
     dir: Direction,
     children: Vec<Element<'a, D>>,
+    flex: Vec<f32>,
+    spacing: f32,
+    justify: Justify,
+    cross_align: Align,
+    keys: KeyHandlers<'a, D, Self>,
 }
 
 impl<'a, D> List<'a, D> {
@@ -18,12 +22,39 @@ impl<'a, D> List<'a, D> {
     pub fn new(dir: Direction) -> Self {
         Self {
             dir,
-            children: Vec::new()
+            children: Vec::new(),
+            flex: Vec::new(),
+            spacing: 0.0,
+            justify: Justify::Start,
+            cross_align: Align::Stretch,
+            keys: KeyHandlers::default(),
         }
     }
 
-    pub fn push(mut self, child: impl Widget<'a, D> + 'a) -> Self {
+    pub fn push(self, child: impl Widget<'a, D> + 'a) -> Self {
+        self.push_flex(child, 1.0)
+    }
+
+    /// Add a child that takes a share of any spare space in proportion to `flex`, or none at all if it's zero.
+    pub fn push_flex(mut self, child: impl Widget<'a, D> + 'a, flex: f32) -> Self {
         self.children.push(child.finish());
+        self.flex.push(flex);
+        self
+    }
+
+    /// Set the space left between neighbouring children.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    pub fn justify(mut self, justify: Justify) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    pub fn cross_align(mut self, align: Align) -> Self {
+        self.cross_align = align;
         self
     }
 
@@ -52,24 +83,27 @@ impl<'a, D> Widget<'a, D> for List<'a, D> {
     fn handle(
         &mut self,
         data: &mut D,
+        env: &Env,
         event: &Event,
         bounds: Bounds,
         resp: &mut Response,
     ) -> bool {
-        self.children
-            .iter_mut()
-            .enumerate()
-            .any(|(i, child)| {
-                child.handle(data, event, resp)
-            })
+        if Element::handle_in_turn(self.children.iter_mut(), data, env, event, resp) {
+            return true;
+        }
+
+        // Key events not consumed by a child go to this list's handlers
+        self.keys.handle(data, event, resp)
     }
 
-    fn get_layout_req(&mut self) -> LayoutReq {
+    fn get_layout_req(&mut self, data: &mut D, env: &Env) -> LayoutReq {
         let dir = self.dir;
+        let mut gaps = [Span::zero(); 2];
+        gaps[dir as usize] = Span::exactly(self.spacing * self.children.len().saturating_sub(1) as f32);
         self.children.iter_mut().fold(
-            LayoutReq::new([Span::zero(); 2]),
+            LayoutReq::new(gaps),
             |l, c| {
-                let c_l = c.get_layout_req();
+                let c_l = c.get_layout_req(data, env);
                 LayoutReq::new(if dir == Direction::Horizontal {[
                     l.width() + c_l.width(),
                     l.height().max(c_l.height()),
@@ -82,35 +116,40 @@ impl<'a, D> Widget<'a, D> for List<'a, D> {
     }
 
     fn fit_bounds(&mut self, bounds: Bounds) {
-        let dir = self.dir;
+        let axis = self.dir as usize;
+        let size = bounds.size();
+        let count = self.children.len();
+        let avail = size[axis] - self.spacing * count.saturating_sub(1) as f32;
 
-        let req_space = self.children.iter().map(|child| child.last_layout_req()[dir as usize].min).sum::<f32>();
-        let spare_space = (bounds.size()[dir as usize] - req_space).max(0.0);
-        let min_factor = (bounds.size()[dir as usize] / req_space).min(1.0);
+        let spans = self.children.iter().map(|child| child.last_layout_req()[axis]).collect::<Vec<_>>();
+        let sizes = layout::distribute(&spans, &self.flex, avail);
+        let spare = (avail - sizes.iter().sum::<f32>()).max(0.0);
 
-        let child_count = self.children.len();
+        let (mut offset, gap) = match self.justify {
+            Justify::Start => (0.0, self.spacing),
+            Justify::Center => (spare / 2.0, self.spacing),
+            Justify::End => (spare, self.spacing),
+            Justify::SpaceBetween if count > 1 => (0.0, self.spacing + spare / (count - 1) as f32),
+            Justify::SpaceBetween => (0.0, self.spacing),
+        };
 
-        let mut offset = 0.0;
-        self.children
-            .iter_mut()
-            .enumerate()
-            .for_each(|(i, child)| {
-                let sz = child.last_layout_req()[dir as usize].min * min_factor + spare_space / child_count as f32;
-                child.fit_bounds(bounds.window(
-                    if dir == Direction::Horizontal { [offset, 0.0] } else { [0.0, offset] },
-                    if dir == Direction::Horizontal {
-                        [sz, bounds.size()[1]]
-                    } else {
-                        [bounds.size()[0], sz]
-                    },
-                ));
-                offset += sz;
-            });
+        let cross_align = self.cross_align;
+        for (child, main) in self.children.iter_mut().zip(sizes) {
+            let (cross_offset, cross) = cross_align.place(child.last_layout_req()[1 - axis], size[1 - axis]);
+            let (mut pos, mut sz) = ([0.0; 2], [0.0; 2]);
+            pos[axis] = offset;
+            sz[axis] = main;
+            pos[1 - axis] = cross_offset;
+            sz[1 - axis] = cross;
+            child.fit_bounds(bounds.window(pos, sz));
+            offset += main + gap;
+        }
     }
 
     fn draw(
         &mut self,
         data: &mut D,
+        env: &Env,
         bounds: Bounds,
         canvas: &mut Canvas,
     ) {
@@ -118,7 +157,19 @@ impl<'a, D> Widget<'a, D> for List<'a, D> {
             .iter_mut()
             .enumerate()
             .for_each(|(i, child)| {
-                child.draw(data, canvas)
+                child.draw(data, env, canvas)
             });
     }
 }
+
+impl<'a, D> Handler<'a, D, KeyPress> for List<'a, D> {
+    fn attach(&mut self, f: impl FnMut(EventCtx<D, KeyPress, Self>) + 'a) {
+        self.keys.press = Some(Box::new(f));
+    }
+}
+
+impl<'a, D> Handler<'a, D, KeyRelease> for List<'a, D> {
+    fn attach(&mut self, f: impl FnMut(EventCtx<D, KeyRelease, Self>) + 'a) {
+        self.keys.release = Some(Box::new(f));
+    }
+}
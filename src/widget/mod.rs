@@ -1,23 +1,48 @@
+pub mod aligned;
 pub mod button;
+pub mod constrained;
+mod edit;
+pub mod for_each;
+pub mod grid;
+pub mod identified;
 pub mod label;
+pub mod lensed;
 pub mod list;
 pub mod padded;
+pub mod scroll_view;
+pub mod stack;
+pub mod styled;
+pub mod text_area;
+pub mod text_box;
 pub mod toggle;
 
 pub use self::{
+    aligned::Aligned,
     button::Button,
+    constrained::Constrained,
+    for_each::ForEach,
+    grid::Grid,
+    identified::Identified,
     label::Label,
+    lensed::Lensed,
     list::List,
     padded::Padded,
+    scroll_view::ScrollView,
+    stack::Stack,
+    styled::Styled,
+    text_area::TextArea,
+    text_box::TextBox,
     toggle::Toggle,
 };
 
 use crate::{
-    event::{EventCtx, Handler},
+    event::{EventCtx, EventKind, Handler},
     element::Element,
-    layout::{Bounds, LayoutReq},
+    layout::{Align, Bounds, Insets, LayoutReq},
+    lens::Lens,
     canvas::Canvas,
-    Event, Response, State,
+    theme::Style,
+    Env, Event, Response, State,
 };
 
 pub trait Widget<'a, D> {
@@ -27,6 +52,8 @@ pub trait Widget<'a, D> {
         Self::from_state(State::Inner(state.into()))
     }
 
+    /// Generate the widget's state from the data with `f`, which runs again each time the widget is updated after the
+    /// data may have changed.
     fn gen_state<S>(f: impl FnMut(&mut D) -> S + 'a) -> Self
         where
             Self: Sized + StateWidget<'a, D, S>,
@@ -46,25 +73,75 @@ pub trait Widget<'a, D> {
     fn bind_state<S>(f: impl for<'b> FnMut(&'b mut D) -> &'b mut S + 'a) -> Self
         where Self: Sized + StateWidget<'a, D, S>,
     {
-        Self::from_state(State::Bind(Box::new(f)))
+        Self::from_state(State::Bind(Box::new(f), None))
     }
 
-    fn on<E>(mut self, event: E, f: impl FnMut(EventCtx<D, E, Self>) + 'a) -> Self
+    /// Bind the widget's state to the part of the data that `lens` projects onto, such as a nested field path made
+    /// with [`Lens::then`].
+    fn bind_lens<S>(lens: impl Lens<D, S> + 'a) -> Self
+        where Self: Sized + StateWidget<'a, D, S>,
+    {
+        Self::from_state(State::Bind(Box::new(lens), None))
+    }
+
+    fn on<E: EventKind>(mut self, event: E, f: impl FnMut(EventCtx<D, E, Self>) + 'a) -> Self
         where Self: Sized + Handler<'a, D, E>
     {
         self.attach(f);
         self
     }
 
-    fn padded(self, padding: f32) -> Padded<'a, D, Self> where Self: Sized {
+    fn padded(self, padding: impl Into<Insets>) -> Padded<'a, D, Self> where Self: Sized {
         Padded::new(self, padding)
     }
 
+    fn align(self, h: Align, v: Align) -> Aligned<'a, D, Self> where Self: Sized {
+        Aligned::new(self, h, v)
+    }
+
+    fn centered(self) -> Aligned<'a, D, Self> where Self: Sized {
+        self.align(Align::Center, Align::Center)
+    }
+
+    fn width(self, width: f32) -> Constrained<'a, D, Self> where Self: Sized {
+        Constrained::new(self).width(width)
+    }
+
+    fn height(self, height: f32) -> Constrained<'a, D, Self> where Self: Sized {
+        Constrained::new(self).height(height)
+    }
+
+    fn min_size(self, size: [f32; 2]) -> Constrained<'a, D, Self> where Self: Sized {
+        Constrained::new(self).min_size(size)
+    }
+
+    fn max_size(self, size: [f32; 2]) -> Constrained<'a, D, Self> where Self: Sized {
+        Constrained::new(self).max_size(size)
+    }
+
+    /// Keep the widget's width divided by its height at `ratio`, which must be positive.
+    fn aspect_ratio(self, ratio: f32) -> Constrained<'a, D, Self> where Self: Sized {
+        Constrained::new(self).aspect_ratio(ratio)
+    }
+
+    fn styled(self, style: Style) -> Styled<'a, D, Self> where Self: Sized {
+        Styled::new(self, style)
+    }
+
+    /// Embed this widget in a tree working on `P`, giving it the part of the data that `lens` projects onto.
+    fn lens<P>(self, lens: impl Lens<P, D> + 'a) -> Lensed<'a, P, D, Self> where Self: Sized {
+        Lensed::new(self, lens)
+    }
+
+    fn identified(self, id: impl Into<String>) -> Identified<'a, D, Self> where Self: Sized {
+        Identified::new(self, id)
+    }
+
     fn finish(self) -> Element<'a, D> where Self: Sized + 'a {
         Element::from_widget(self)
     }
 
-    fn get_layout_req(&mut self) -> LayoutReq;
+    fn get_layout_req(&mut self, data: &mut D, env: &Env) -> LayoutReq;
 
     fn fit_bounds(&mut self, bounds: Bounds) {}
 
@@ -72,9 +149,23 @@ pub trait Widget<'a, D> {
         Box::new(std::iter::empty())
     }
 
+    fn find_bounds(&mut self, id: &str) -> Option<Bounds> {
+        self.children().find_map(|child| child.find_bounds(id))
+    }
+
+    /// Bring the widget's state up to date after the data may have changed, returning whether anything that affects
+    /// its layout or appearance (including that of its children) may be different.
+    fn update(&mut self, data: &mut D) -> bool {
+        self.children().fold(false, |changed, child| child.update(data) | changed)
+    }
+
+    /// Handle an event, returning whether it was consumed. Anything that changes the widget's appearance other than
+    /// its data, such as hovering or pressing it, must be followed by [`Response::redraw`], or it will keep being
+    /// drawn as before.
     fn handle(
         &mut self,
         data: &mut D,
+        env: &Env,
         event: &Event,
         bounds: Bounds,
         resp: &mut Response,
@@ -83,6 +174,7 @@ pub trait Widget<'a, D> {
     fn draw(
         &mut self,
         data: &mut D,
+        env: &Env,
         bounds: Bounds,
         canvas: &mut Canvas,
     ) {}
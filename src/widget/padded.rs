@@ -1,22 +1,22 @@
 use crate::{
     draw::Color,
     canvas::Canvas,
-    layout::{Bounds, LayoutReq},
-    event::{Handle, Handler, Click, EventCtx},
-    Widget, State, Event, Element, Response,
+    layout::{Bounds, Insets, LayoutReq},
+    event::{Handle, Handler, Click, EventCtx, EventKind},
+    Env, Widget, State, Event, Element, Response,
 };
 use std::marker::PhantomData;
 
 pub struct Padded<'a, D, W: Widget<'a, D>> {
-    padding: f32,
+    padding: Insets,
     inner: W,
     phantom: PhantomData<&'a D>,
 }
 
 impl<'a, D, W: Widget<'a, D>> Padded<'a, D, W> {
-    pub fn new(inner: W, padding: f32) -> Self {
+    pub fn new(inner: W, padding: impl Into<Insets>) -> Self {
         Self {
-            padding,
+            padding: padding.into(),
             inner,
             phantom: PhantomData,
         }
@@ -24,19 +24,32 @@ impl<'a, D, W: Widget<'a, D>> Padded<'a, D, W> {
 }
 
 impl<'a, D, W: Widget<'a, D>> Widget<'a, D> for Padded<'a, D, W> {
+    fn children(&mut self) -> Box<dyn Iterator<Item=&mut Element<'a, D>> + '_> {
+        self.inner.children()
+    }
+
+    fn update(&mut self, data: &mut D) -> bool {
+        self.inner.update(data)
+    }
+
+    fn find_bounds(&mut self, id: &str) -> Option<Bounds> {
+        self.inner.find_bounds(id)
+    }
+
     fn handle(
         &mut self,
         data: &mut D,
+        env: &Env,
         event: &Event,
         bounds: Bounds,
         resp: &mut Response,
     ) -> bool {
-        self.inner.handle(data, event, bounds.padded_window(self.padding), resp)
+        self.inner.handle(data, env, event, bounds.padded_window(self.padding), resp)
     }
 
-    fn get_layout_req(&mut self) -> LayoutReq {
+    fn get_layout_req(&mut self, data: &mut D, env: &Env) -> LayoutReq {
         self.inner
-            .get_layout_req()
+            .get_layout_req(data, env)
             .padded(self.padding)
     }
 
@@ -47,14 +60,15 @@ impl<'a, D, W: Widget<'a, D>> Widget<'a, D> for Padded<'a, D, W> {
     fn draw(
         &mut self,
         data: &mut D,
+        env: &Env,
         bounds: Bounds,
         canvas: &mut Canvas,
     ) {
-        self.inner.draw(data, bounds.padded_window(self.padding), canvas)
+        self.inner.draw(data, env, bounds.padded_window(self.padding), canvas)
     }
 }
 
-impl<'a, D, E, W: Widget<'a, D> + Handler<'a, D, E>> Handler<'a, D, E> for Padded<'a, D, W> {
+impl<'a, D, E: EventKind, W: Widget<'a, D> + Handler<'a, D, E>> Handler<'a, D, E> for Padded<'a, D, W> {
     fn attach(&mut self, mut f: impl FnMut(EventCtx<D, E, Self>) + 'a) {
         self.inner.attach(move |ctx| f(ctx.map()));
     }
@@ -0,0 +1,247 @@
+use crate::{
+    canvas::Canvas,
+    layout::{Bounds, Direction, LayoutReq, Span},
+    event::{Handler, KeyHandlers, KeyPress, KeyRelease, EventCtx},
+    Env, Widget, Event, Element, Response, MouseButton,
+};
+
+const BAR_WIDTH: f32 = 10.0;
+const MIN_THUMB: f32 = 16.0;
+/// How far a single notch of the scroll wheel scrolls.
+const SCROLL_STEP: f32 = 40.0;
+
+pub struct ScrollView<'a, D> {
+    dir: Direction,
+    offset: f32,
+    content: f32,
+    viewport: Bounds,
+    is_bar_hover: bool,
+    is_dragging: bool,
+    inner: Option<Element<'a, D>>,
+    keys: KeyHandlers<'a, D, Self>,
+}
+
+impl<'a, D> ScrollView<'a, D> {
+    pub fn horizontal() -> Self { Self::new(Direction::Horizontal) }
+    pub fn vertical() -> Self { Self::new(Direction::Vertical) }
+
+    pub fn new(dir: Direction) -> Self {
+        Self {
+            dir,
+            offset: 0.0,
+            content: 0.0,
+            viewport: Bounds::global([0.0; 2]),
+            is_bar_hover: false,
+            is_dragging: false,
+            inner: None,
+            keys: KeyHandlers::default(),
+        }
+    }
+
+    pub fn containing(mut self, inner: impl Widget<'a, D> + 'a) -> Self {
+        self.inner = Some(inner.finish());
+        self
+    }
+
+    fn axis(&self) -> usize { self.dir as usize }
+
+    fn max_offset(&self) -> f32 {
+        (self.content - self.viewport.size()[self.axis()]).max(0.0)
+    }
+
+    /// The bounds of the scrollbar track, alongside the viewport.
+    fn track(&self, bounds: Bounds) -> Bounds {
+        let (pos, size) = (bounds.pos(), bounds.size());
+        if self.dir == Direction::Horizontal {
+            Bounds::new([pos[0], pos[1] + size[1] - BAR_WIDTH], [size[0], BAR_WIDTH])
+        } else {
+            Bounds::new([pos[0] + size[0] - BAR_WIDTH, pos[1]], [BAR_WIDTH, size[1]])
+        }
+    }
+
+    /// The offset and length of the scrollbar thumb along the track.
+    fn thumb(&self) -> (f32, f32) {
+        let track = self.viewport.size()[self.axis()];
+        let len = if self.content > 0.0 {
+            (track * track / self.content).max(MIN_THUMB).min(track)
+        } else {
+            track
+        };
+        let max_offset = self.max_offset();
+        let pos = if max_offset > 0.0 { (track - len) * self.offset / max_offset } else { 0.0 };
+        (pos, len)
+    }
+
+    fn scroll_to(&mut self, offset: f32) {
+        self.offset = offset.min(self.max_offset()).max(0.0);
+
+        let axis = self.axis();
+        let mut pos = self.viewport.pos();
+        let mut size = self.viewport.size();
+        pos[axis] -= self.offset;
+        size[axis] = self.content.max(size[axis]);
+        if let Some(inner) = &mut self.inner {
+            inner.fit_bounds(Bounds::new(pos, size));
+        }
+    }
+}
+
+impl<'a, D> Widget<'a, D> for ScrollView<'a, D> {
+    fn children(&mut self) -> Box<dyn Iterator<Item=&mut Element<'a, D>> + '_> {
+        Box::new(self.inner.as_mut().into_iter())
+    }
+
+    fn handle(
+        &mut self,
+        data: &mut D,
+        env: &Env,
+        event: &Event,
+        bounds: Bounds,
+        resp: &mut Response,
+    ) -> bool {
+        let axis = self.axis();
+        let track = self.track(bounds);
+
+        // Dragging the scrollbar
+        match event {
+            Event::MouseDown(pos, MouseButton::Left) if track.contains(*pos) => {
+                let (thumb_pos, thumb_len) = self.thumb();
+                let along = pos[axis] - track.pos()[axis];
+                if along < thumb_pos || along > thumb_pos + thumb_len {
+                    // Jump so that the thumb is centred on the cursor
+                    let free = track.size()[axis] - thumb_len;
+                    if free > 0.0 {
+                        self.scroll_to((along - thumb_len / 2.0) / free * self.max_offset());
+                    }
+                }
+                self.is_dragging = true;
+                resp.capture();
+                resp.redraw();
+                return true;
+            },
+            Event::Drag { delta, button: MouseButton::Left, .. } if self.is_dragging => {
+                let (_, thumb_len) = self.thumb();
+                let free = track.size()[axis] - thumb_len;
+                if free > 0.0 {
+                    self.scroll_to(self.offset + delta[axis] / free * self.max_offset());
+                    resp.redraw();
+                }
+                return true;
+            },
+            Event::MouseUp(..) if self.is_dragging => {
+                self.is_dragging = false;
+                resp.redraw();
+                return true;
+            },
+            Event::CursorMove(pos) => {
+                let old_hover = self.is_bar_hover;
+                self.is_bar_hover = track.contains(*pos);
+                if old_hover != self.is_bar_hover {
+                    resp.redraw();
+                }
+            },
+            _ => {},
+        }
+
+        // Content scrolled out of view must not react to the pointer, unless it's holding the pointer captive
+        let outside = event.pos().is_some_and(|pos| !self.viewport.contains(pos));
+
+        let inner = match &mut self.inner {
+            Some(inner) => inner,
+            None => return self.keys.handle(data, event, resp),
+        };
+        let handled = if outside && !resp.is_captured() {
+            match event {
+                Event::CursorMove(_) => inner.handle(data, env, &Event::CursorMove([f32::INFINITY; 2]), resp),
+                Event::Click(_, button) => inner.handle(data, env, &Event::Click([f32::INFINITY; 2], *button), resp),
+                _ => false,
+            }
+        } else {
+            inner.handle(data, env, event, resp)
+        };
+
+        if handled {
+            true
+        } else if let Event::Scroll(pos, delta) = event {
+            if bounds.contains(*pos) {
+                // Vertical wheels are used for horizontal views too
+                let delta = if delta[axis] == 0.0 { delta[1] } else { delta[axis] };
+                let old_offset = self.offset;
+                self.scroll_to(self.offset - delta * SCROLL_STEP);
+                if self.offset != old_offset {
+                    resp.redraw();
+                }
+                true
+            } else {
+                false
+            }
+        } else {
+            self.keys.handle(data, event, resp)
+        }
+    }
+
+    fn get_layout_req(&mut self, data: &mut D, env: &Env) -> LayoutReq {
+        let axis = self.axis();
+        let inner = self.inner
+            .as_mut()
+            .map(|i| i.get_layout_req(data, env))
+            .unwrap_or(LayoutReq::any());
+        self.content = inner[axis].min;
+
+        let mut span = [Span::fill(); 2];
+        span[1 - axis] = inner[1 - axis] + Span::exactly(BAR_WIDTH);
+        LayoutReq::new(span)
+    }
+
+    fn fit_bounds(&mut self, bounds: Bounds) {
+        let axis = self.axis();
+        let mut size = bounds.size();
+        size[1 - axis] = (size[1 - axis] - BAR_WIDTH).max(0.0);
+        self.viewport = Bounds::new(bounds.pos(), size);
+        self.scroll_to(self.offset);
+    }
+
+    fn draw(
+        &mut self,
+        data: &mut D,
+        env: &Env,
+        bounds: Bounds,
+        canvas: &mut Canvas,
+    ) {
+        if let Some(inner) = &mut self.inner {
+            canvas.clipped(self.viewport, |canvas| inner.draw(data, env, canvas));
+        }
+
+        let axis = self.axis();
+        let track = self.track(bounds);
+        let (thumb_pos, thumb_len) = self.thumb();
+        let theme = env.theme();
+        let mut track_canvas = canvas.bounded(track);
+        track_canvas.fill(theme.palette.track);
+        if self.max_offset() > 0.0 {
+            let mut pos = [0.0; 2];
+            let mut size = [BAR_WIDTH; 2];
+            pos[axis] = thumb_pos;
+            size[axis] = thumb_len;
+            track_canvas.draw_rect(pos, size, if self.is_dragging {
+                theme.palette.thumb_active
+            } else if self.is_bar_hover {
+                theme.palette.thumb_hover
+            } else {
+                theme.palette.thumb
+            });
+        }
+    }
+}
+
+impl<'a, D> Handler<'a, D, KeyPress> for ScrollView<'a, D> {
+    fn attach(&mut self, f: impl FnMut(EventCtx<D, KeyPress, Self>) + 'a) {
+        self.keys.press = Some(Box::new(f));
+    }
+}
+
+impl<'a, D> Handler<'a, D, KeyRelease> for ScrollView<'a, D> {
+    fn attach(&mut self, f: impl FnMut(EventCtx<D, KeyRelease, Self>) + 'a) {
+        self.keys.release = Some(Box::new(f));
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{
+    canvas::Canvas,
+    layout::{Align, Bounds, LayoutReq, Span},
+    event::{Handler, KeyHandlers, KeyPress, KeyRelease, EventCtx},
+    Env, Widget, Event, Element, Response,
+};
+
+/// Layers children on top of each other, with later children drawn above earlier ones.
+pub struct Stack<'a, D> {
+    children: Vec<Element<'a, D>>,
+    /// The horizontal and vertical alignment of each child.
+    align: Vec<[Align; 2]>,
+    keys: KeyHandlers<'a, D, Self>,
+}
+
+impl<'a, D> Stack<'a, D> {
+    pub fn new() -> Self {
+        Self {
+            children: Vec::new(),
+            align: Vec::new(),
+            keys: KeyHandlers::default(),
+        }
+    }
+
+    /// Add a layer that covers the whole stack.
+    pub fn push(self, child: impl Widget<'a, D> + 'a) -> Self {
+        self.push_aligned(child, Align::Stretch, Align::Stretch)
+    }
+
+    pub fn push_aligned(mut self, child: impl Widget<'a, D> + 'a, h: Align, v: Align) -> Self {
+        self.children.push(child.finish());
+        self.align.push([h, v]);
+        self
+    }
+}
+
+impl<'a, D> Default for Stack<'a, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, D> Widget<'a, D> for Stack<'a, D> {
+    fn children(&mut self) -> Box<dyn Iterator<Item=&mut Element<'a, D>> + '_> {
+        Box::new(self.children.iter_mut())
+    }
+
+    fn handle(
+        &mut self,
+        data: &mut D,
+        env: &Env,
+        event: &Event,
+        _bounds: Bounds,
+        resp: &mut Response,
+    ) -> bool {
+        // The top-most layer gets the first chance at every event
+        Element::handle_in_turn(self.children.iter_mut().rev(), data, env, event, resp)
+            || self.keys.handle(data, event, resp)
+    }
+
+    fn get_layout_req(&mut self, data: &mut D, env: &Env) -> LayoutReq {
+        // Big enough for the biggest layer, only limited if every layer is
+        let reqs = self.children
+            .iter_mut()
+            .map(|child| child.get_layout_req(data, env))
+            .collect::<Vec<_>>();
+        let span = |axis: usize| Span {
+            min: reqs.iter().map(|req| req[axis].min).fold(0.0, f32::max),
+            max: reqs.iter().map(|req| req[axis].max).try_fold(0.0, |a, max| Some(max?.max(a))),
+        };
+        LayoutReq::new([span(0), span(1)])
+    }
+
+    fn fit_bounds(&mut self, bounds: Bounds) {
+        let size = bounds.size();
+        for (child, align) in self.children.iter_mut().zip(&self.align) {
+            let req = child.last_layout_req();
+            let (x, w) = align[0].place(req[0], size[0]);
+            let (y, h) = align[1].place(req[1], size[1]);
+            child.fit_bounds(bounds.window([x, y], [w, h]));
+        }
+    }
+
+    fn draw(
+        &mut self,
+        data: &mut D,
+        env: &Env,
+        _bounds: Bounds,
+        canvas: &mut Canvas,
+    ) {
+        self.children
+            .iter_mut()
+            .for_each(|child| child.draw(data, env, canvas));
+    }
+}
+
+impl<'a, D> Handler<'a, D, KeyPress> for Stack<'a, D> {
+    fn attach(&mut self, f: impl FnMut(EventCtx<D, KeyPress, Self>) + 'a) {
+        self.keys.press = Some(Box::new(f));
+    }
+}
+
+impl<'a, D> Handler<'a, D, KeyRelease> for Stack<'a, D> {
+    fn attach(&mut self, f: impl FnMut(EventCtx<D, KeyRelease, Self>) + 'a) {
+        self.keys.release = Some(Box::new(f));
+    }
+}
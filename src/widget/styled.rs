@@ -0,0 +1,92 @@
+use crate::{
+    canvas::Canvas,
+    layout::{Bounds, LayoutReq},
+    event::{EventCtx, EventKind, Handler},
+    theme::Style,
+    Env, Widget, Event, Element, Response,
+};
+use std::marker::PhantomData;
+
+pub struct Styled<'a, D, W: Widget<'a, D>> {
+    style: Style,
+    /// The environment last given to the subtree, along with the one it was derived from, so that the styled theme is
+    /// only built again when the outer one changes.
+    env: Option<(Env, Env)>,
+    inner: W,
+    phantom: PhantomData<&'a D>,
+}
+
+impl<'a, D, W: Widget<'a, D>> Styled<'a, D, W> {
+    pub fn new(inner: W, style: Style) -> Self {
+        Self {
+            style,
+            env: None,
+            inner,
+            phantom: PhantomData,
+        }
+    }
+
+    /// The environment of the subtree, with the style applied to its theme.
+    fn env(&mut self, env: &Env) -> Env {
+        match &self.env {
+            Some((outer, inner)) if outer.same_as(env) => inner.clone(),
+            _ => {
+                let inner = env.clone().with_theme(self.style.apply(env.theme()));
+                self.env = Some((env.clone(), inner.clone()));
+                inner
+            },
+        }
+    }
+}
+
+impl<'a, D, W: Widget<'a, D>> Widget<'a, D> for Styled<'a, D, W> {
+    fn children(&mut self) -> Box<dyn Iterator<Item=&mut Element<'a, D>> + '_> {
+        self.inner.children()
+    }
+
+    fn update(&mut self, data: &mut D) -> bool {
+        self.inner.update(data)
+    }
+
+    fn find_bounds(&mut self, id: &str) -> Option<Bounds> {
+        self.inner.find_bounds(id)
+    }
+
+    fn handle(
+        &mut self,
+        data: &mut D,
+        env: &Env,
+        event: &Event,
+        bounds: Bounds,
+        resp: &mut Response,
+    ) -> bool {
+        let env = self.env(env);
+        self.inner.handle(data, &env, event, bounds, resp)
+    }
+
+    fn get_layout_req(&mut self, data: &mut D, env: &Env) -> LayoutReq {
+        let env = self.env(env);
+        self.inner.get_layout_req(data, &env)
+    }
+
+    fn fit_bounds(&mut self, bounds: Bounds) {
+        self.inner.fit_bounds(bounds)
+    }
+
+    fn draw(
+        &mut self,
+        data: &mut D,
+        env: &Env,
+        bounds: Bounds,
+        canvas: &mut Canvas,
+    ) {
+        let env = self.env(env);
+        self.inner.draw(data, &env, bounds, canvas)
+    }
+}
+
+impl<'a, D, E: EventKind, W: Widget<'a, D> + Handler<'a, D, E>> Handler<'a, D, E> for Styled<'a, D, W> {
+    fn attach(&mut self, mut f: impl FnMut(EventCtx<D, E, Self>) + 'a) {
+        self.inner.attach(move |ctx| f(ctx.map()));
+    }
+}
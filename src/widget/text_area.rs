@@ -0,0 +1,254 @@
+use crate::{
+    canvas::Canvas,
+    layout::{Bounds, LayoutReq, Span},
+    Env, Widget, StateWidget, State, Event, Response, Key,
+};
+use super::edit::{self, Cursor, UsedKeys};
+use std::ops::Range;
+
+/// Wrap text to fit inside a text area of the given width.
+fn wrap(text: &str, width: f32, env: &Env) -> Vec<Range<usize>> {
+    let theme = env.theme();
+    edit::wrap(text, (width - theme.padding * 2.0).max(0.0), env.metrics(), theme.text_height)
+}
+
+pub struct TextArea<'a, D> {
+    is_focused: bool,
+    cursor: Cursor,
+    used_keys: UsedKeys,
+    /// The horizontal position that vertical caret movement tries to stay at.
+    goal_x: Option<f32>,
+    scroll: f32,
+    follow_caret: bool,
+    /// The width of the text area's bounds, whose text wraps within it less the padding.
+    width: f32,
+    line_count: usize,
+    rows: [usize; 2],
+    state: State<'a, D, String>,
+}
+
+impl<'a, D> TextArea<'a, D> {
+    /// Set the minimum and maximum number of lines that the text area asks for room to display.
+    pub fn rows(mut self, min: usize, max: usize) -> Self {
+        self.rows = [min, max.max(min)];
+        self
+    }
+
+    fn move_vertically(&mut self, text: &str, env: &Env, down: bool, extend: bool) {
+        let theme = env.theme();
+        let lines = wrap(text, self.width, env);
+        let idx = edit::line_of(&lines, self.cursor.caret);
+        let line = &lines[idx];
+        let x = self.goal_x.unwrap_or_else(|| env.metrics().width_of(&text[line.start..self.cursor.caret], theme.text_height));
+
+        let pos = match (down, idx) {
+            (false, 0) => 0,
+            (true, idx) if idx + 1 == lines.len() => text.len(),
+            (down, idx) => {
+                let target = lines[if down { idx + 1 } else { idx - 1 }].clone();
+                let offset = target.start + edit::offset_at(&text[target.clone()], x, env.metrics(), theme.text_height);
+                // Don't let the caret land after the break of a soft-wrapped line, it would show on the next line
+                if offset == target.end && target.end < text.len() && !text[target.end..].starts_with('\n') {
+                    edit::prev_char(text, offset)
+                } else {
+                    offset
+                }
+            },
+        };
+        self.cursor.move_to(pos, extend);
+        self.goal_x = Some(x);
+    }
+}
+
+impl<'a, D> StateWidget<'a, D, String> for TextArea<'a, D> {
+    fn from_state(state: State<'a, D, String>) -> Self {
+        Self {
+            is_focused: false,
+            cursor: Cursor::default(),
+            used_keys: UsedKeys::default(),
+            goal_x: None,
+            scroll: 0.0,
+            follow_caret: false,
+            // Until the first `fit_bounds`, only break lines at newlines
+            width: f32::INFINITY,
+            line_count: 1,
+            rows: [3, 8],
+            state,
+        }
+    }
+}
+
+impl<'a, D> Widget<'a, D> for TextArea<'a, D> {
+    fn update(&mut self, data: &mut D) -> bool {
+        self.state.update(data)
+    }
+
+    fn handle(
+        &mut self,
+        data: &mut D,
+        env: &Env,
+        event: &Event,
+        bounds: Bounds,
+        resp: &mut Response,
+    ) -> bool {
+        let theme = env.theme();
+        match event {
+            // Clicks are never consumed so that every other text box gets the chance to lose focus
+            Event::Click(pos, _) => {
+                let was_focused = self.is_focused;
+                self.is_focused = bounds.contains(*pos);
+                if self.is_focused {
+                    let text = self.state.get_mut(data);
+                    self.cursor.clamp(text);
+                    let lines = wrap(text, self.width, env);
+                    let rel = [pos[0] - bounds.pos()[0] - theme.padding, pos[1] - bounds.pos()[1] - theme.padding];
+                    let idx = ((rel[1] + self.scroll) / theme.text_height).max(0.0) as usize;
+                    let line = lines[idx.min(lines.len() - 1)].clone();
+                    let offset = line.start + edit::offset_at(&text[line.clone()], rel[0], env.metrics(), theme.text_height);
+                    self.cursor.move_to(offset, false);
+                    self.goal_x = None;
+                }
+                if self.is_focused || was_focused {
+                    resp.redraw();
+                }
+                false
+            },
+            Event::Text(s) if self.is_focused => {
+                let text = self.state.get_mut(data);
+                self.cursor.clamp(text);
+                self.cursor.insert(text, s);
+                self.goal_x = None;
+                self.follow_caret = true;
+                resp.mark_changed();
+                resp.redraw();
+                true
+            },
+            Event::KeyPress(key) if self.is_focused => {
+                let text = self.state.get_mut(data);
+                self.cursor.clamp(text);
+                let extend = key.modifiers.shift;
+                match key.key {
+                    Key::Up | Key::Down => {
+                        let text = text.clone();
+                        self.move_vertically(&text, env, key.key == Key::Down, extend);
+                    },
+                    Key::Home | Key::End => {
+                        let pos = if key.modifiers.ctrl {
+                            if key.key == Key::Home { 0 } else { text.len() }
+                        } else {
+                            let lines = wrap(text, self.width, env);
+                            let line = &lines[edit::line_of(&lines, self.cursor.caret)];
+                            if key.key == Key::Home { line.start } else { line.end }
+                        };
+                        self.cursor.move_to(pos, extend);
+                        self.goal_x = None;
+                    },
+                    Key::Enter | Key::NumPadEnter => {
+                        self.cursor.insert(text, "\n");
+                        self.goal_x = None;
+                    },
+                    Key::Escape => self.is_focused = false,
+                    _ => if self.cursor.handle_key(text, key) {
+                        self.goal_x = None;
+                    } else {
+                        return false;
+                    },
+                }
+                self.follow_caret = true;
+                self.used_keys.press(key.key);
+                resp.mark_changed();
+                resp.redraw();
+                true
+            },
+            Event::Scroll(pos, delta) if bounds.contains(*pos) => {
+                let max_scroll = (self.line_count as f32 * theme.text_height - (bounds.size()[1] - theme.padding * 2.0)).max(0.0);
+                let scroll = (self.scroll - delta[1] * theme.text_height * 3.0).min(max_scroll).max(0.0);
+                if scroll != self.scroll {
+                    self.scroll = scroll;
+                    resp.redraw();
+                    true
+                } else {
+                    // Let any scrolling container around us take over once we can't scroll any further
+                    false
+                }
+            },
+            // Swallow the releases of keys that we used
+            Event::KeyRelease(key) => self.used_keys.release(key.key),
+            _ => false,
+        }
+    }
+
+    fn get_layout_req(&mut self, data: &mut D, env: &Env) -> LayoutReq {
+        let theme = env.theme();
+        self.line_count = wrap(self.state.get_mut(data), self.width, env).len();
+        let rows = self.line_count.max(self.rows[0]).min(self.rows[1]);
+        LayoutReq::new([
+            Span::min(96.0),
+            Span::min(rows as f32 * theme.text_height + theme.padding * 2.0),
+        ])
+    }
+
+    fn fit_bounds(&mut self, bounds: Bounds) {
+        self.width = bounds.size()[0];
+    }
+
+    fn draw(
+        &mut self,
+        data: &mut D,
+        env: &Env,
+        bounds: Bounds,
+        canvas: &mut Canvas,
+    ) {
+        let theme = env.theme();
+        let text = self.state.get_mut(data);
+        self.cursor.clamp(text);
+
+        let lines = wrap(text, self.width, env);
+        let caret_line = edit::line_of(&lines, self.cursor.caret);
+
+        // Scroll so that the caret is visible, but only after it has been moved so that the view can be scrolled away
+        let visible = (bounds.size()[1] - theme.padding * 2.0).max(theme.text_height);
+        if std::mem::take(&mut self.follow_caret) {
+            let caret_y = caret_line as f32 * theme.text_height;
+            self.scroll = self.scroll
+                .min(caret_y)
+                .max(caret_y + theme.text_height - visible);
+        }
+        self.scroll = self.scroll
+            .min(lines.len() as f32 * theme.text_height - visible)
+            .max(0.0);
+
+        let mut canvas = canvas.bounded(bounds);
+        theme.draw_frame(&mut canvas, theme.palette.surface);
+
+        let is_focused = self.is_focused;
+        let selection = self.cursor.selection().filter(|_| is_focused);
+        let (scroll, caret) = (self.scroll, self.cursor.caret);
+        let text_size = [bounds.size()[0] - theme.padding * 2.0, visible];
+        canvas.clipped([theme.padding; 2], text_size, |canvas| for (i, line) in lines.iter().enumerate() {
+            let y = i as f32 * theme.text_height - scroll;
+            if y + theme.text_height <= 0.0 || y >= visible {
+                continue;
+            }
+
+            if let Some(sel) = &selection {
+                let (start, end) = (sel.start.max(line.start), sel.end.min(line.end));
+                // Highlight the newline too if the selection continues onto the next line
+                let past_break = sel.start <= line.end && sel.end > line.end && text[line.end..].starts_with('\n');
+                if start < end || past_break {
+                    let x0 = env.metrics().width_of(&text[line.start..start], theme.text_height);
+                    let x1 = env.metrics().width_of(&text[line.start..end], theme.text_height)
+                        + if past_break { env.metrics().width_of(" ", theme.text_height) } else { 0.0 };
+                    canvas.draw_rect([theme.padding + x0, theme.padding + y], [x1 - x0, theme.text_height], theme.palette.selection);
+                }
+            }
+
+            canvas.draw_text([theme.padding, theme.padding + y], &text[line.clone()], theme.text_height, theme.palette.text);
+
+            if is_focused && i == caret_line {
+                let x = theme.padding + env.metrics().width_of(&text[line.start..caret], theme.text_height);
+                canvas.draw_line([x, theme.padding + y], [x, theme.padding + y + theme.text_height], theme.palette.text);
+            }
+        });
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{
+    canvas::Canvas,
+    layout::{Bounds, LayoutReq, Span},
+    event::{Handle, Handler, Submit, EventCtx},
+    Env, Widget, StateWidget, State, Event, Response, Key,
+};
+use super::edit::{self, Cursor, UsedKeys};
+
+pub struct TextBox<'a, D> {
+    is_focused: bool,
+    cursor: Cursor,
+    used_keys: UsedKeys,
+    state: State<'a, D, String>,
+    on_submit: Handle<'a, D, Submit, Self>,
+}
+
+impl<'a, D> StateWidget<'a, D, String> for TextBox<'a, D> {
+    fn from_state(state: State<'a, D, String>) -> Self {
+        Self {
+            is_focused: false,
+            cursor: Cursor::default(),
+            used_keys: UsedKeys::default(),
+            state,
+            on_submit: None,
+        }
+    }
+}
+
+impl<'a, D> Widget<'a, D> for TextBox<'a, D> {
+    fn update(&mut self, data: &mut D) -> bool {
+        self.state.update(data)
+    }
+
+    fn handle(
+        &mut self,
+        data: &mut D,
+        env: &Env,
+        event: &Event,
+        bounds: Bounds,
+        resp: &mut Response,
+    ) -> bool {
+        let theme = env.theme();
+        match event {
+            // Clicks are never consumed so that every other text box gets the chance to lose focus
+            Event::Click(pos, _) => {
+                let was_focused = self.is_focused;
+                self.is_focused = bounds.contains(*pos);
+                if self.is_focused {
+                    let text = self.state.get_mut(data);
+                    let x = pos[0] - bounds.pos()[0] - theme.padding;
+                    self.cursor.move_to(edit::offset_at(text, x, env.metrics(), theme.text_height), false);
+                }
+                if self.is_focused || was_focused {
+                    resp.redraw();
+                }
+                false
+            },
+            Event::Text(s) if self.is_focused => {
+                let text = self.state.get_mut(data);
+                self.cursor.clamp(text);
+                self.cursor.insert(text, s);
+                resp.mark_changed();
+                resp.redraw();
+                true
+            },
+            Event::KeyPress(key) if self.is_focused => {
+                let text = self.state.get_mut(data);
+                self.cursor.clamp(text);
+                match key.key {
+                    Key::Home => self.cursor.move_to(0, key.modifiers.shift),
+                    Key::End => self.cursor.move_to(text.len(), key.modifiers.shift),
+                    Key::Enter | Key::NumPadEnter => if let Some(f) = self.on_submit.as_mut() {
+                        f(EventCtx {
+                            widget: std::marker::PhantomData,
+                            event: &Submit,
+                            data,
+                        });
+                    },
+                    Key::Escape => self.is_focused = false,
+                    _ => if !self.cursor.handle_key(text, key) {
+                        return false;
+                    },
+                }
+                self.used_keys.press(key.key);
+                resp.mark_changed();
+                resp.redraw();
+                true
+            },
+            // Swallow the releases of keys that we used
+            Event::KeyRelease(key) => self.used_keys.release(key.key),
+            _ => false,
+        }
+    }
+
+    fn get_layout_req(&mut self, _data: &mut D, env: &Env) -> LayoutReq {
+        let theme = env.theme();
+        LayoutReq::new([
+            Span::min(96.0),
+            Span::exactly(theme.text_height + theme.padding * 2.0),
+        ])
+    }
+
+    fn draw(
+        &mut self,
+        data: &mut D,
+        env: &Env,
+        bounds: Bounds,
+        canvas: &mut Canvas,
+    ) {
+        let theme = env.theme();
+        let text = self.state.get_mut(data);
+        self.cursor.clamp(text);
+
+        let mut canvas = canvas.bounded(bounds);
+        theme.draw_frame(&mut canvas, theme.palette.surface);
+
+        if self.is_focused {
+            if let Some(range) = self.cursor.selection() {
+                let start = env.metrics().width_of(&text[..range.start], theme.text_height);
+                let end = env.metrics().width_of(&text[..range.end], theme.text_height);
+                canvas.draw_rect([theme.padding + start, theme.padding], [end - start, theme.text_height], theme.palette.selection);
+            }
+        }
+
+        canvas.draw_text([theme.padding; 2], text.clone(), theme.text_height, theme.palette.text);
+
+        if self.is_focused {
+            let x = theme.padding + env.metrics().width_of(&text[..self.cursor.caret], theme.text_height);
+            canvas.draw_line([x, theme.padding], [x, theme.padding + theme.text_height], theme.palette.text);
+        }
+    }
+}
+
+impl<'a, D> Handler<'a, D, Submit> for TextBox<'a, D> {
+    fn attach(&mut self, f: impl FnMut(EventCtx<D, Submit, Self>) + 'a) {
+        self.on_submit = Some(Box::new(f));
+    }
+}
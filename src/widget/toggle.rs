@@ -1,9 +1,8 @@
 use crate::{
-    draw::Color,
     canvas::Canvas,
     layout::{Bounds, LayoutReq},
-    event::{Handle, Handler, Click, EventCtx},
-    Widget, StateWidget, State, Event, Element, Response,
+    event::{Handle, Handler, Click, KeyHandlers, KeyPress, KeyRelease, EventCtx},
+    Env, Widget, StateWidget, State, Event, Element, Response, MouseButton,
 };
 
 pub struct Toggle<'a, D> {
@@ -12,6 +11,7 @@ pub struct Toggle<'a, D> {
     state: State<'a, D, bool>,
     inner: Option<Element<'a, D>>,
     on_click: Handle<'a, D, Click, Self>,
+    keys: KeyHandlers<'a, D, Self>,
 }
 
 impl<'a, D> Toggle<'a, D> {
@@ -29,6 +29,7 @@ impl<'a, D> StateWidget<'a, D, bool> for Toggle<'a, D> {
             inner: None,
             state,
             on_click: None,
+            keys: KeyHandlers::default(),
         }
     }
 }
@@ -38,16 +39,22 @@ impl<'a, D> Widget<'a, D> for Toggle<'a, D> {
         Box::new(self.inner.as_mut().into_iter())
     }
 
+    fn update(&mut self, data: &mut D) -> bool {
+        let inner = self.inner.as_mut().is_some_and(|inner| inner.update(data));
+        self.state.update(data) | inner
+    }
+
     fn handle(
         &mut self,
         data: &mut D,
+        env: &Env,
         event: &Event,
         bounds: Bounds,
         resp: &mut Response,
     ) -> bool {
         if self.inner
             .as_mut()
-            .map(|x| x.handle(data, event, resp))
+            .map(|x| x.handle(data, env, event, resp))
             .unwrap_or(false)
         {
             true
@@ -58,13 +65,30 @@ impl<'a, D> Widget<'a, D> for Toggle<'a, D> {
                     *state ^= true;
                     self.on_click.as_mut().map(|f| f(EventCtx {
                         widget: std::marker::PhantomData,//self,
+                        event: &Click,
                         data,
                     }));
+                    resp.mark_changed();
                     resp.redraw();
                     true
                 } else {
                     false
                 }
+            } else if let Event::MouseDown(pos, MouseButton::Left) = event {
+                if bounds.contains(*pos) {
+                    self.is_pressed = true;
+                    resp.capture();
+                    resp.redraw();
+                    true
+                } else {
+                    false
+                }
+            } else if let Event::MouseUp(_, MouseButton::Left) = event {
+                if self.is_pressed {
+                    self.is_pressed = false;
+                    resp.redraw();
+                }
+                false
             } else if let Event::CursorMove(pos) = event {
                 let old_hover = self.is_hover;
                 self.is_hover = bounds.contains(*pos);
@@ -73,15 +97,15 @@ impl<'a, D> Widget<'a, D> for Toggle<'a, D> {
                 }
                 false
             } else {
-                false
+                self.keys.handle(data, event, resp)
             }
         }
     }
 
-    fn get_layout_req(&mut self) -> LayoutReq {
+    fn get_layout_req(&mut self, data: &mut D, env: &Env) -> LayoutReq {
         self.inner
             .as_mut()
-            .map(|i| i.get_layout_req())
+            .map(|i| i.get_layout_req(data, env))
             .unwrap_or(LayoutReq::any())
     }
 
@@ -92,21 +116,23 @@ impl<'a, D> Widget<'a, D> for Toggle<'a, D> {
     fn draw(
         &mut self,
         data: &mut D,
+        env: &Env,
         bounds: Bounds,
         canvas: &mut Canvas,
     ) {
-        canvas.bounded(bounds).fill(if self.is_pressed {
-            Color::RED
+        let theme = env.theme();
+        theme.draw_frame(&mut canvas.bounded(bounds), if self.is_pressed {
+            theme.palette.pressed
         } else if self.is_hover {
-            Color::YELLOW
+            theme.palette.hover
         } else if *self.state.get_mut(data) {
-            Color::BLUE
+            theme.palette.accent
         } else {
-            Color::WHITE
+            theme.palette.surface
         });
         self.inner
             .as_mut()
-            .map(|x| x.draw(data, canvas));
+            .map(|x| x.draw(data, env, canvas));
     }
 }
 
@@ -115,3 +141,15 @@ impl<'a, D> Handler<'a, D, Click> for Toggle<'a, D> {
         self.on_click = Some(Box::new(f));
     }
 }
+
+impl<'a, D> Handler<'a, D, KeyPress> for Toggle<'a, D> {
+    fn attach(&mut self, f: impl FnMut(EventCtx<D, KeyPress, Self>) + 'a) {
+        self.keys.press = Some(Box::new(f));
+    }
+}
+
+impl<'a, D> Handler<'a, D, KeyRelease> for Toggle<'a, D> {
+    fn attach(&mut self, f: impl FnMut(EventCtx<D, KeyRelease, Self>) + 'a) {
+        self.keys.release = Some(Box::new(f));
+    }
+}
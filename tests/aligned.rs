@@ -0,0 +1,52 @@
+use gui::{
+    layout::Align,
+    widget::{Button, Label},
+    event::Click,
+    harness::Harness,
+    MouseButton, Widget,
+};
+
+/// Where a label 18 wide and 20 high ends up in a 100 by 100 space.
+fn place(h: Align, v: Align) -> ([f32; 2], [f32; 2]) {
+    let ui = Label::with_state("ab").identified("label").align(h, v);
+    let bounds = Harness::new(ui, [100.0, 100.0], ()).bounds_of("label").unwrap();
+    (bounds.pos(), bounds.size())
+}
+
+#[test]
+fn children_are_placed_at_their_alignment() {
+    assert_eq!(place(Align::Start, Align::Start), ([0.0, 0.0], [18.0, 20.0]));
+    assert_eq!(place(Align::Center, Align::Center), ([41.0, 40.0], [18.0, 20.0]));
+    assert_eq!(place(Align::End, Align::End), ([82.0, 80.0], [18.0, 20.0]));
+    assert_eq!(place(Align::End, Align::Start), ([82.0, 0.0], [18.0, 20.0]));
+}
+
+#[test]
+fn stretching_stops_at_the_maximum_size() {
+    assert_eq!(place(Align::Stretch, Align::Center), ([0.0, 40.0], [18.0, 20.0]));
+
+    // Without a maximum, stretching fills the space
+    let ui = Button::<()>::default_state().identified("button").align(Align::Stretch, Align::Stretch);
+    let bounds = Harness::new(ui, [100.0, 100.0], ()).bounds_of("button").unwrap();
+    assert_eq!((bounds.pos(), bounds.size()), ([0.0, 0.0], [100.0, 100.0]));
+}
+
+#[test]
+fn children_are_drawn_where_they_are_placed() {
+    let ui = Label::<()>::with_state("ab").centered();
+    let dump = Harness::new(ui, [100.0, 100.0], ()).draw().dump();
+    assert!(dump.contains("text pos=(41, 40)"));
+}
+
+#[test]
+fn only_clicks_on_the_placed_child_reach_it() {
+    let ui = Button::<u32>::default_state()
+        .containing(Label::with_state("ab"))
+        .on(Click, |ctx| *ctx.data += 1)
+        .centered();
+    let mut h = Harness::new(ui, [100.0, 100.0], 0);
+    h.click([10.0, 10.0], MouseButton::Left);
+    assert_eq!(*h.data(), 0);
+    h.click([50.0, 50.0], MouseButton::Left);
+    assert_eq!(*h.data(), 1);
+}
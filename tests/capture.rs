@@ -0,0 +1,58 @@
+use gui::{
+    widget::{Button, Label, List, TextBox},
+    event::Click,
+    harness::Harness,
+    MouseButton, Widget,
+};
+
+#[derive(Default)]
+struct Data {
+    text: String,
+    clicks: usize,
+}
+
+fn text_box<'a>() -> impl Widget<'a, Data> {
+    TextBox::<Data>::bind_state(|d| &mut d.text).identified("box")
+}
+
+fn button<'a>() -> impl Widget<'a, Data> {
+    Button::<Data>::default_state()
+        .containing(Label::<Data>::with_state("Go"))
+        .on(Click, |ctx| ctx.data.clicks += 1)
+        .identified("button")
+}
+
+#[test]
+fn clicking_a_button_unfocuses_a_text_box() {
+    let mut h = Harness::new(List::vertical().push(text_box()).push(button()), [200.0, 100.0], Data::default());
+    h.click_on("box");
+    h.type_text("a");
+    h.click_on("button");
+    h.type_text("b");
+    assert_eq!(h.data().text, "a");
+    assert_eq!(h.data().clicks, 1);
+}
+
+#[test]
+fn clicking_a_button_unfocuses_a_text_box_after_it() {
+    let mut h = Harness::new(List::vertical().push(button()).push(text_box()), [200.0, 100.0], Data::default());
+    h.click_on("box");
+    h.type_text("a");
+    h.click_on("button");
+    h.type_text("b");
+    assert_eq!(h.data().text, "a");
+    assert_eq!(h.data().clicks, 1);
+}
+
+#[test]
+fn capture_lasts_until_its_own_button_is_released() {
+    let mut h = Harness::new(List::vertical().push(button()).push(text_box()), [200.0, 100.0], Data::default());
+    let pos = h.center_of("button");
+    let elsewhere = h.center_of("box");
+    h.mouse_down(pos, MouseButton::Left);
+    h.mouse_down(elsewhere, MouseButton::Right);
+    h.mouse_up(elsewhere, MouseButton::Right);
+    assert!(h.cursor_move(elsewhere).is_captured());
+    h.mouse_up(elsewhere, MouseButton::Left);
+    assert!(!h.cursor_move(elsewhere).is_captured());
+}
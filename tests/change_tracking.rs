@@ -0,0 +1,205 @@
+use gui::{
+    canvas::Canvas,
+    layout::{Align, Bounds, LayoutReq, Span},
+    widget::{Button, ForEach, Label, List},
+    harness::Harness,
+    theme::Style,
+    Env, Event, Response, State, Widget,
+};
+use std::{cell::Cell, rc::Rc};
+
+#[derive(Default)]
+struct Data {
+    a: String,
+    b: String,
+}
+
+#[derive(Clone, Default)]
+struct Counts {
+    layouts: Rc<Cell<usize>>,
+    draws: Rc<Cell<usize>>,
+}
+
+impl Counts {
+    fn get(&self) -> (usize, usize) {
+        (self.layouts.get(), self.draws.get())
+    }
+}
+
+/// Shows a bound string, counting how many times it's laid out and drawn.
+struct Probe<'a, D> {
+    state: State<'a, D, String>,
+    counts: Counts,
+}
+
+impl<'a, D> Probe<'a, D> {
+    fn bind(f: impl for<'b> FnMut(&'b mut D) -> &'b mut String + 'a, counts: &Counts) -> Self {
+        Self { state: State::Bind(Box::new(f), None), counts: counts.clone() }
+    }
+}
+
+impl<'a, D> Widget<'a, D> for Probe<'a, D> {
+    fn update(&mut self, data: &mut D) -> bool {
+        self.state.update(data)
+    }
+
+    fn get_layout_req(&mut self, _data: &mut D, _env: &Env) -> LayoutReq {
+        self.counts.layouts.set(self.counts.layouts.get() + 1);
+        LayoutReq::new([Span::exactly(50.0), Span::exactly(20.0)])
+    }
+
+    fn draw(&mut self, data: &mut D, env: &Env, bounds: Bounds, canvas: &mut Canvas) {
+        self.counts.draws.set(self.counts.draws.get() + 1);
+        let theme = env.theme();
+        canvas.bounded(bounds).draw_text([0.0; 2], self.state.get_mut(data).clone(), theme.text_height, theme.palette.text);
+    }
+}
+
+fn probes<'a>(a: &Counts, b: &Counts) -> impl Widget<'a, Data> {
+    List::vertical()
+        .push_flex(Probe::bind(|d: &mut Data| &mut d.a, a), 0.0)
+        .push_flex(Probe::bind(|d: &mut Data| &mut d.b, b), 0.0)
+}
+
+#[test]
+fn only_changed_widgets_are_laid_out_and_drawn_again() {
+    let (a, b) = (Counts::default(), Counts::default());
+    let mut h = Harness::new(probes(&a, &b), [200.0, 200.0], Data::default());
+    h.draw();
+    assert_eq!((a.get(), b.get()), ((1, 1), (1, 1)));
+
+    h.data_mut().a = "changed".to_string();
+    let canvas = h.draw();
+    assert_eq!((a.get(), b.get()), ((2, 2), (1, 1)));
+    // The unchanged widget's primitives are reused rather than lost
+    assert_eq!(canvas.dump().matches("text ").count(), 2);
+    assert!(canvas.dump().contains("\"changed\""));
+}
+
+#[test]
+fn nothing_is_redone_when_the_data_is_untouched() {
+    let (a, b) = (Counts::default(), Counts::default());
+    let mut h = Harness::new(probes(&a, &b), [200.0, 200.0], Data::default());
+    let first = h.draw().dump();
+
+    // Borrowing the data mutably without changing it isn't a change
+    h.data_mut();
+    assert_eq!(h.draw().dump(), first);
+    assert_eq!((a.get(), b.get()), ((1, 1), (1, 1)));
+}
+
+#[test]
+fn hovering_a_widget_redraws_only_it() {
+    let counts = Counts::default();
+    let ui = List::vertical()
+        .push_flex(Button::<Data>::default_state().containing(Label::with_state("Go")).identified("button"), 0.0)
+        .push_flex(Probe::bind(|d: &mut Data| &mut d.a, &counts), 0.0);
+    let mut h = Harness::new(ui, [200.0, 200.0], Data::default());
+    let before = h.draw().dump();
+
+    assert!(h.hover_on("button").needs_redraw());
+    assert_ne!(h.draw().dump(), before);
+    assert_eq!(counts.get(), (1, 1));
+}
+
+/// Change the data behind `ui`, a bound label within a wrapper, and check that the new text is drawn.
+fn shows_changes_through<'a>(ui: impl Widget<'a, Data> + 'a) {
+    let mut h = Harness::new(ui, [200.0, 200.0], Data::default());
+    h.draw();
+    h.data_mut().a = "changed".to_string();
+    assert!(h.draw().dump().contains("\"changed\""));
+}
+
+fn bound_label<'a>() -> Label<'a, Data> {
+    Label::bind_state(|d: &mut Data| &mut d.a)
+}
+
+#[test]
+fn changes_reach_wrapped_widgets() {
+    shows_changes_through(bound_label().padded(16.0));
+    shows_changes_through(bound_label().align(Align::End, Align::Center));
+    shows_changes_through(bound_label().centered());
+    shows_changes_through(bound_label().width(100.0).height(30.0));
+    shows_changes_through(bound_label().identified("label"));
+    shows_changes_through(bound_label().styled(Style::default()));
+    shows_changes_through(bound_label().padded(16.0).centered().identified("screen"));
+}
+
+#[test]
+fn generated_state_is_only_generated_again_after_changes() {
+    let runs = Rc::new(Cell::new(0));
+    let counter = runs.clone();
+    let ui = Label::gen_state(move |d: &mut Data| {
+        counter.set(counter.get() + 1);
+        format!("{}!", d.a)
+    }).identified("label");
+    let mut h = Harness::new(ui, [200.0, 200.0], Data::default());
+    h.draw();
+    h.cursor_move([10.0, 10.0]);
+    h.bounds_of("label");
+    h.draw();
+    assert_eq!(runs.get(), 1);
+
+    h.data_mut().a = "Hi".to_string();
+    assert!(h.draw().dump().contains("\"Hi!\""));
+    assert_eq!(runs.get(), 2);
+}
+
+#[test]
+fn generated_items_follow_the_data() {
+    let ui = ForEach::<Data, String>::gen_state(|d| d.a.split(' ').map(str::to_string).collect())
+        .each(|| Label::bind_state(|s: &mut String| s));
+    let mut h = Harness::new(ui, [200.0, 200.0], Data { a: "one two".to_string(), b: String::new() });
+    assert!(h.draw().dump().contains("\"two\""));
+
+    h.data_mut().a = "one two three".to_string();
+    assert!(h.draw().dump().contains("\"three\""));
+}
+
+/// Flips its colour when clicked, only asking to be redrawn if `redraw` is set.
+struct Flipper {
+    on: bool,
+    redraw: bool,
+}
+
+impl<'a, D> Widget<'a, D> for Flipper {
+    fn get_layout_req(&mut self, _data: &mut D, _env: &Env) -> LayoutReq {
+        LayoutReq::new([Span::exactly(20.0), Span::exactly(20.0)])
+    }
+
+    fn handle(&mut self, _data: &mut D, _env: &Env, event: &Event, bounds: Bounds, resp: &mut Response) -> bool {
+        match event {
+            Event::Click(pos, _) if bounds.contains(*pos) => {
+                self.on = !self.on;
+                if self.redraw {
+                    resp.redraw();
+                }
+                true
+            },
+            _ => false,
+        }
+    }
+
+    fn draw(&mut self, _data: &mut D, env: &Env, bounds: Bounds, canvas: &mut Canvas) {
+        let palette = &env.theme().palette;
+        canvas.bounded(bounds).fill(if self.on { palette.text } else { palette.background });
+    }
+}
+
+fn flip(redraw: bool) -> (String, String) {
+    let ui = Flipper { on: false, redraw }.identified("flipper");
+    let mut h = Harness::new(ui, [20.0, 20.0], Data::default());
+    let before = h.draw().dump();
+    h.click_on("flipper");
+    (before, h.draw().dump())
+}
+
+#[test]
+fn appearance_changes_need_a_redraw_to_be_seen() {
+    let (before, after) = flip(true);
+    assert_ne!(before, after);
+
+    // Without asking to be redrawn, what the widget last drew is reused
+    let (before, after) = flip(false);
+    assert_eq!(before, after);
+}
@@ -0,0 +1,70 @@
+use gui::{
+    layout::Align,
+    widget::{Button, Label, List},
+    event::Click,
+    harness::Harness,
+    MouseButton, Widget,
+};
+
+fn label<'a, D>() -> Label<'a, D> {
+    Label::with_state("")
+}
+
+#[test]
+fn parents_make_room_for_the_minimum_size() {
+    let ui = label().identified("label").min_size([80.0, 60.0]).align(Align::Start, Align::Start);
+    let h = &mut Harness::new(ui, [200.0, 200.0], ());
+    assert_eq!(h.bounds_of("label").unwrap().size(), [80.0, 60.0]);
+
+    let ui = List::vertical()
+        .push_flex(label().identified("label").min_size([80.0, 60.0]), 0.0)
+        .push_flex(label(), 1.0);
+    let h = &mut Harness::new(ui, [200.0, 200.0], ());
+    assert_eq!(h.bounds_of("label").unwrap().size(), [80.0, 60.0]);
+}
+
+#[test]
+fn widgets_stay_within_their_bounds() {
+    let ui = label().identified("label").min_size([80.0, 60.0]);
+    let h = &mut Harness::new(ui, [50.0, 40.0], ());
+    assert_eq!(h.bounds_of("label").unwrap().size(), [50.0, 40.0]);
+}
+
+#[test]
+fn clicks_only_land_where_the_widget_is_drawn() {
+    let ui = Button::<u32>::default_state()
+        .on(Click, |ctx| *ctx.data += 1)
+        .min_size([80.0, 60.0]);
+    let h = &mut Harness::new(ui, [50.0, 40.0], 0);
+    h.click([70.0, 50.0], MouseButton::Left);
+    assert_eq!(*h.data(), 0);
+    h.click([40.0, 30.0], MouseButton::Left);
+    assert_eq!(*h.data(), 1);
+}
+
+#[test]
+fn maximums_win_over_minimums() {
+    let ui = label().identified("label").min_size([80.0, 60.0]).max_size([70.0, 70.0]).align(Align::Start, Align::Start);
+    let h = &mut Harness::new(ui, [200.0, 200.0], ());
+    assert_eq!(h.bounds_of("label").unwrap().size(), [70.0, 60.0]);
+}
+
+#[test]
+fn aspect_ratio_asks_for_the_minimum_size() {
+    let ui = label().identified("label").min_size([40.0, 40.0]).aspect_ratio(2.0).align(Align::Start, Align::Start);
+    let h = &mut Harness::new(ui, [200.0, 200.0], ());
+    assert_eq!(h.bounds_of("label").unwrap().size(), [80.0, 40.0]);
+}
+
+#[test]
+fn aspect_ratio_shrinks_to_fit() {
+    let ui = label().identified("label").aspect_ratio(2.0);
+    let h = &mut Harness::new(ui, [200.0, 30.0], ());
+    assert_eq!(h.bounds_of("label").unwrap().size(), [60.0, 30.0]);
+}
+
+#[test]
+#[should_panic(expected = "aspect ratio must be positive")]
+fn aspect_ratio_must_be_positive() {
+    label::<()>().aspect_ratio(0.0);
+}
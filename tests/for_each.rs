@@ -0,0 +1,146 @@
+use gui::{
+    layout::{Align, LayoutReq},
+    widget::{ForEach, Label, List, TextBox},
+    harness::Harness,
+    Env, Widget,
+};
+use std::{cell::Cell, rc::Rc};
+
+fn names<'a>() -> impl Widget<'a, Vec<String>> {
+    List::vertical()
+        .push_flex(ForEach::<Vec<String>, String>::bind_state(|names| names).each(|| Label::bind_state(|s: &mut String| s)), 0.0)
+        .push_flex(Label::with_state("end").identified("end"), 0.0)
+}
+
+#[test]
+fn children_for_new_items_are_laid_out_before_drawing() {
+    let mut h = Harness::new(names(), [200.0, 200.0], vec!["Ada".to_string()]);
+    assert_eq!(h.bounds_of("end").unwrap().pos()[1], 20.0);
+
+    h.data_mut().push("Grace".to_string());
+    let dump = h.draw().dump();
+    assert!(dump.contains("\"Grace\""));
+    assert!(!dump.contains("size=(0, 0)"));
+    assert_eq!(h.bounds_of("end").unwrap().pos()[1], 40.0);
+}
+
+#[test]
+fn items_are_as_wide_as_the_widest() {
+    let ui = ForEach::<Vec<String>, String>::bind_state(|names| names)
+        .each(|| Label::bind_state(|s: &mut String| s))
+        .identified("names")
+        .align(Align::Start, Align::Start);
+    let mut h = Harness::new(ui, [200.0, 200.0], vec!["Ada".to_string(), "Grace".to_string()]);
+    // Labels ask for exactly their width, so the list can only be as wide as the widest of them
+    assert_eq!(h.bounds_of("names").unwrap().size(), [5.0 * 20.0 * 0.45, 40.0]);
+}
+
+#[derive(Clone)]
+struct Entry {
+    id: u32,
+    text: String,
+}
+
+fn entries(ids: &[u32]) -> Vec<Entry> {
+    ids.iter().map(|&id| Entry { id, text: String::new() }).collect()
+}
+
+fn text_boxes<'a>() -> impl Widget<'a, Vec<Entry>> {
+    ForEach::<Vec<Entry>, Entry>::bind_state(|entries| entries)
+        .key(|entry| entry.id)
+        .each(|| TextBox::bind_state(|e: &mut Entry| &mut e.text))
+}
+
+/// Click the text box of the item at the given index.
+fn focus(h: &mut Harness<Vec<Entry>>, index: usize) {
+    let pos = [50.0, index as f32 * 28.0 + 14.0];
+    h.cursor_move(pos);
+    h.click(pos, gui::MouseButton::Left);
+}
+
+fn texts<'h>(h: &'h Harness<Vec<Entry>>) -> Vec<(u32, &'h str)> {
+    h.data().iter().map(|e| (e.id, e.text.as_str())).collect()
+}
+
+#[test]
+fn inserting_an_item_keeps_the_focused_child_with_its_item() {
+    let mut h = Harness::new(text_boxes(), [200.0, 200.0], entries(&[1, 2, 3]));
+    focus(&mut h, 1);
+    h.data_mut().insert(1, Entry { id: 4, text: String::new() });
+    h.type_text("x");
+    assert_eq!(texts(&h), vec![(1, ""), (4, ""), (2, "x"), (3, "")]);
+}
+
+#[test]
+fn events_reach_children_for_new_items() {
+    let mut h = Harness::new(text_boxes(), [200.0, 200.0], entries(&[1]));
+    h.data_mut().push(Entry { id: 2, text: String::new() });
+    focus(&mut h, 1);
+    h.type_text("x");
+    assert_eq!(texts(&h), vec![(1, ""), (2, "x")]);
+}
+
+#[test]
+fn events_after_a_removal_miss_the_removed_items_child() {
+    let mut h = Harness::new(text_boxes(), [200.0, 200.0], entries(&[1, 2, 3]));
+    focus(&mut h, 0);
+    h.data_mut().remove(0);
+    // The focused text box went with its item, so typing reaches nothing
+    h.type_text("x");
+    assert_eq!(texts(&h), vec![(2, ""), (3, "")]);
+}
+
+#[test]
+fn reordering_items_moves_their_children() {
+    let mut h = Harness::new(text_boxes(), [200.0, 200.0], entries(&[1, 2, 3]));
+    focus(&mut h, 0);
+    h.data_mut().reverse();
+    h.type_text("x");
+    assert_eq!(texts(&h), vec![(3, ""), (2, ""), (1, "x")]);
+    // The focused text box is drawn where its item now is
+    assert!(h.draw().dump().contains("text pos=(4, 60) height=20 col=#000000ff \"x\""));
+}
+
+/// Counts how many of its kind have been dropped.
+struct Tracked(Rc<Cell<usize>>);
+
+impl<'a> Widget<'a, Entry> for Tracked {
+    fn get_layout_req(&mut self, _data: &mut Entry, _env: &Env) -> LayoutReq {
+        LayoutReq::any()
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn removing_an_item_drops_its_child() {
+    let dropped = Rc::new(Cell::new(0));
+    let counter = dropped.clone();
+    let ui = ForEach::<Vec<Entry>, Entry>::bind_state(|entries| entries)
+        .key(|entry| entry.id)
+        .each(move || Tracked(counter.clone()));
+    let mut h = Harness::new(ui, [200.0, 200.0], entries(&[1, 2, 3]));
+    assert_eq!(dropped.get(), 0);
+
+    h.data_mut().remove(1);
+    h.draw();
+    assert_eq!(dropped.get(), 1);
+}
+
+#[test]
+#[should_panic(expected = "ForEach keys must be unique")]
+fn duplicate_keys_are_caught() {
+    Harness::new(text_boxes(), [200.0, 200.0], entries(&[1, 1]));
+}
+
+#[test]
+#[should_panic(expected = "ForEach keys must be unique")]
+fn duplicate_keys_are_caught_when_items_change() {
+    let mut h = Harness::new(text_boxes(), [200.0, 200.0], entries(&[1, 2]));
+    h.data_mut()[1].id = 1;
+    h.draw();
+}
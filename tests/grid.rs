@@ -0,0 +1,111 @@
+use gui::{
+    layout::Align,
+    widget::{grid::Track, Grid, Label},
+    harness::Harness,
+    Widget,
+};
+
+/// A label of `chars` characters, each 9 wide with the harness's metrics.
+fn label<'a>(id: &str, chars: usize) -> impl Widget<'a, ()> {
+    Label::with_state("x".repeat(chars)).identified(id)
+}
+
+fn assert_near(a: [f32; 2], b: [f32; 2]) {
+    assert!((a[0] - b[0]).abs() < 0.01 && (a[1] - b[1]).abs() < 0.01, "{:?} != {:?}", a, b);
+}
+
+/// The x position and width of each widget.
+fn columns(h: &mut Harness<()>, ids: &[&str]) -> Vec<[f32; 2]> {
+    ids.iter()
+        .map(|id| {
+            let bounds = h.bounds_of(id).unwrap();
+            [bounds.pos()[0], bounds.size()[0]]
+        })
+        .collect()
+}
+
+#[test]
+fn fractions_split_the_space_left_by_fixed_tracks() {
+    let ui = Grid::new()
+        .columns([Track::Fixed(50.0), Track::Fraction(1.0), Track::Fraction(2.0)])
+        .gap(10.0)
+        .cell(0, 0, label("a", 1))
+        .cell(0, 1, label("b", 8))
+        .cell(0, 2, label("c", 1));
+    let h = &mut Harness::new(ui, [380.0, 100.0], ());
+    let cols = columns(h, &["a", "b", "c"]);
+    assert_near(cols[0], [0.0, 50.0]);
+    assert_near(cols[1], [60.0, 310.0 / 3.0]);
+    assert_near(cols[2], [70.0 + 310.0 / 3.0, 620.0 / 3.0]);
+}
+
+#[test]
+fn equal_fractions_are_equal_whatever_their_contents() {
+    let ui = Grid::new()
+        .columns([Track::Fraction(1.0), Track::Fraction(1.0)])
+        .cell(0, 0, label("a", 1))
+        .cell(0, 1, label("b", 10));
+    let h = &mut Harness::new(ui, [300.0, 100.0], ());
+    assert_eq!(columns(h, &["a", "b"]), vec![[0.0, 150.0], [150.0, 150.0]]);
+}
+
+#[test]
+fn fractions_never_shrink_below_their_contents() {
+    let ui = Grid::new()
+        .columns([Track::Fraction(1.0), Track::Fraction(1.0)])
+        .cell(0, 0, label("a", 1))
+        .cell(0, 1, label("b", 7));
+    let h = &mut Harness::new(ui, [100.0, 100.0], ());
+    assert_eq!(columns(h, &["a", "b"]), vec![[0.0, 37.0], [37.0, 63.0]]);
+}
+
+#[test]
+fn grids_ask_for_enough_room_to_keep_fractions_in_proportion() {
+    let ui = Grid::new()
+        .columns([Track::Fixed(10.0), Track::Fraction(1.0), Track::Fraction(3.0)])
+        .cell(0, 1, label("a", 2))
+        .cell(0, 2, label("b", 2))
+        .identified("grid")
+        .align(Align::Start, Align::Start);
+    let h = &mut Harness::new(ui, [300.0, 100.0], ());
+    // The first fraction needs 18 for its contents, so the second needs three times that
+    assert_eq!(h.bounds_of("grid").unwrap().size()[0], 10.0 + 18.0 * 4.0);
+}
+
+#[test]
+fn auto_tracks_fit_their_contents() {
+    let ui = Grid::new()
+        .columns([Track::Auto, Track::Fraction(1.0)])
+        .cell(0, 0, label("a", 3))
+        .cell(0, 1, label("b", 1));
+    let h = &mut Harness::new(ui, [200.0, 100.0], ());
+    assert_eq!(columns(h, &["a", "b"]), vec![[0.0, 27.0], [27.0, 173.0]]);
+}
+
+#[test]
+fn spanning_cells_cover_their_tracks_and_the_gaps_between() {
+    let ui = Grid::new()
+        .columns([Track::Fixed(40.0), Track::Fixed(60.0)])
+        .rows([Track::Fixed(30.0), Track::Fixed(30.0)])
+        .gaps(10.0, 5.0)
+        .cell(0, 0, label("a", 1))
+        .cell(0, 1, label("b", 1))
+        .span(1, 0, 1, 2, label("wide", 1));
+    let h = &mut Harness::new(ui, [200.0, 100.0], ());
+    let b = h.bounds_of("b").unwrap();
+    assert_eq!((b.pos(), b.size()), ([50.0, 0.0], [60.0, 30.0]));
+    let wide = h.bounds_of("wide").unwrap();
+    assert_eq!((wide.pos(), wide.size()), ([0.0, 35.0], [110.0, 30.0]));
+}
+
+#[test]
+fn spanning_cells_grow_the_tracks_they_cover() {
+    let ui = Grid::new()
+        .columns([Track::Auto, Track::Auto])
+        .cell(0, 0, label("a", 1))
+        .cell(0, 1, label("b", 1))
+        .span(1, 0, 1, 2, label("wide", 10));
+    let h = &mut Harness::new(ui, [200.0, 100.0], ());
+    // Both columns grow evenly from the 9 that their own cells need, until the 90 wide cell fits
+    assert_eq!(columns(h, &["a", "b", "wide"]), vec![[0.0, 45.0], [45.0, 45.0], [0.0, 90.0]]);
+}
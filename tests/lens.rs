@@ -0,0 +1,93 @@
+use gui::{
+    lens::Field,
+    widget::{Label, List, TextBox},
+    harness::Harness,
+    Lens, Widget,
+};
+
+#[derive(Default, Lens)]
+struct Person {
+    name: String,
+    age: u32,
+}
+
+impl Person {
+    // A getter named after a field must not collide with the generated lens
+    #[allow(dead_code)]
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[derive(Default, Lens)]
+struct App {
+    title: String,
+    left: Person,
+    right: Person,
+}
+
+#[test]
+fn derived_lenses_bind_state() {
+    let app = App { title: "People".to_string(), ..App::default() };
+    let mut h = Harness::new(Label::bind_state(App::lens_title), [200.0, 40.0], app);
+    assert!(h.draw().dump().contains("\"People\""));
+}
+
+#[test]
+fn derived_lenses_embed_subtrees() {
+    let person = || TextBox::<Person>::bind_state(Person::lens_name).identified("name");
+    let ui = List::<App>::horizontal()
+        .push(person().lens(App::lens_left))
+        .push(TextBox::bind_lens(App::lens_right.then(Person::lens_name)).identified("right"));
+    let mut h = Harness::new(ui, [400.0, 40.0], App::default());
+
+    h.click_on("name");
+    h.type_text("Ada");
+    h.click_on("right");
+    h.type_text("Grace");
+    assert_eq!(h.data().left.name, "Ada");
+    assert_eq!(h.data().right.name, "Grace");
+}
+
+#[test]
+fn lenses_chain() {
+    let mut app = App::default();
+    let mut age = App::lens_left.then(Person::lens_age);
+    *age.get_mut(&mut app) = 36;
+    let mut name = Field::new(|app: &mut App| &mut app.right).then(Person::lens_name);
+    name.get_mut(&mut app).push_str("Grace");
+    assert_eq!(app.left.age, 36);
+    assert_eq!(app.right.name, "Grace");
+}
+
+#[derive(Lens)]
+struct Borrowed<'s> {
+    inner: Inner<'s>,
+}
+
+#[derive(Lens)]
+struct Inner<'s> {
+    text: &'s mut String,
+}
+
+#[test]
+fn lenses_chain_through_borrowed_data() {
+    let mut text = String::new();
+    let mut data = Borrowed { inner: Inner { text: &mut text } };
+    let mut lens = Borrowed::lens_inner.then(Inner::lens_text);
+    lens.get_mut(&mut data).push_str("borrowed");
+    assert_eq!(text, "borrowed");
+}
+
+#[derive(Default, Lens)]
+struct Pair<T> {
+    first: T,
+    second: T,
+}
+
+#[test]
+fn lenses_are_derived_for_generic_structs() {
+    let mut pair = Pair::<u8>::default();
+    *Pair::lens_second(&mut pair) = 2;
+    assert_eq!((pair.first, pair.second), (0, 2));
+}
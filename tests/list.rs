@@ -0,0 +1,86 @@
+use gui::{
+    layout::{Align, Justify},
+    widget::{Button, Identified, List},
+    harness::Harness,
+    Widget,
+};
+
+/// A widget that takes whatever space it's given.
+fn filler<'a>(id: &str) -> Identified<'a, (), Button<'a, ()>> {
+    Button::default_state().identified(id)
+}
+
+/// The position and size along the main axis of each widget.
+fn placed(h: &mut Harness<()>, axis: usize, ids: &[&str]) -> Vec<[f32; 2]> {
+    ids.iter()
+        .map(|id| {
+            let bounds = h.bounds_of(id).unwrap();
+            [bounds.pos()[axis], bounds.size()[axis]]
+        })
+        .collect()
+}
+
+#[test]
+fn spare_space_is_shared_by_flex() {
+    let ui = List::horizontal()
+        .push_flex(filler("a"), 1.0)
+        .push_flex(filler("b"), 2.0)
+        .push_flex(filler("c").width(30.0), 0.0);
+    let h = &mut Harness::new(ui, [330.0, 100.0], ());
+    assert_eq!(placed(h, 0, &["a", "b", "c"]), vec![[0.0, 100.0], [100.0, 200.0], [300.0, 30.0]]);
+}
+
+#[test]
+fn flex_stops_at_the_maximum_size() {
+    let ui = List::horizontal()
+        .push_flex(filler("a").max_size([50.0, 100.0]), 1.0)
+        .push_flex(filler("b"), 1.0);
+    let h = &mut Harness::new(ui, [300.0, 100.0], ());
+    assert_eq!(placed(h, 0, &["a", "b"]), vec![[0.0, 50.0], [50.0, 250.0]]);
+}
+
+fn justified(justify: Justify) -> Vec<[f32; 2]> {
+    let ui = List::vertical()
+        .justify(justify)
+        .push_flex(filler("a").height(50.0), 0.0)
+        .push_flex(filler("b").height(50.0), 0.0);
+    let h = &mut Harness::new(ui, [100.0, 300.0], ());
+    placed(h, 1, &["a", "b"])
+}
+
+#[test]
+fn justify_places_children_along_the_main_axis() {
+    assert_eq!(justified(Justify::Start), vec![[0.0, 50.0], [50.0, 50.0]]);
+    assert_eq!(justified(Justify::Center), vec![[100.0, 50.0], [150.0, 50.0]]);
+    assert_eq!(justified(Justify::End), vec![[200.0, 50.0], [250.0, 50.0]]);
+    assert_eq!(justified(Justify::SpaceBetween), vec![[0.0, 50.0], [250.0, 50.0]]);
+}
+
+fn cross_aligned(align: Align) -> Vec<[f32; 2]> {
+    let ui = List::horizontal()
+        .cross_align(align)
+        .push_flex(filler("a").height(20.0), 1.0)
+        .push_flex(filler("b"), 1.0);
+    let h = &mut Harness::new(ui, [200.0, 100.0], ());
+    placed(h, 1, &["a", "b"])
+}
+
+#[test]
+fn cross_align_places_children_across_the_list() {
+    assert_eq!(cross_aligned(Align::Start), vec![[0.0, 20.0], [0.0, 0.0]]);
+    assert_eq!(cross_aligned(Align::Center), vec![[40.0, 20.0], [50.0, 0.0]]);
+    assert_eq!(cross_aligned(Align::End), vec![[80.0, 20.0], [100.0, 0.0]]);
+    // Stretching stops at each child's maximum
+    assert_eq!(cross_aligned(Align::Stretch), vec![[0.0, 20.0], [0.0, 100.0]]);
+}
+
+#[test]
+fn spacing_separates_neighbours() {
+    let ui = List::horizontal()
+        .spacing(10.0)
+        .push_flex(filler("a").width(50.0), 0.0)
+        .push_flex(filler("b"), 1.0)
+        .push_flex(filler("c").width(50.0), 0.0);
+    let h = &mut Harness::new(ui, [300.0, 100.0], ());
+    assert_eq!(placed(h, 0, &["a", "b", "c"]), vec![[0.0, 50.0], [60.0, 180.0], [250.0, 50.0]]);
+}
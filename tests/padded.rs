@@ -0,0 +1,48 @@
+use gui::{
+    layout::{Align, Insets},
+    widget::{Button, Identified, Label},
+    harness::Harness,
+    Widget,
+};
+
+/// A widget that takes whatever space it's given.
+fn filler<'a>(id: &str) -> Identified<'a, (), Button<'a, ()>> {
+    Button::default_state().identified(id)
+}
+
+fn placed(h: &mut Harness<()>, id: &str) -> ([f32; 2], [f32; 2]) {
+    let bounds = h.bounds_of(id).unwrap();
+    (bounds.pos(), bounds.size())
+}
+
+#[test]
+fn each_side_is_inset_separately() {
+    let h = &mut Harness::new(filler("inner").padded(Insets::new(10.0, 0.0, 0.0, 5.0)), [100.0, 100.0], ());
+    assert_eq!(placed(h, "inner"), ([10.0, 0.0], [90.0, 95.0]));
+
+    let h = &mut Harness::new(filler("inner").padded(Insets::symmetric(20.0, 5.0)), [100.0, 100.0], ());
+    assert_eq!(placed(h, "inner"), ([20.0, 5.0], [60.0, 90.0]));
+}
+
+#[test]
+fn a_single_number_insets_every_side() {
+    let h = &mut Harness::new(filler("inner").padded(8.0), [100.0, 100.0], ());
+    assert_eq!(placed(h, "inner"), ([8.0, 8.0], [84.0, 84.0]));
+}
+
+#[test]
+fn insets_add_to_the_size_asked_for() {
+    // The label is 18 by 20 with the harness's metrics
+    let ui = Label::with_state("ab")
+        .padded(Insets::new(10.0, 2.0, 4.0, 6.0))
+        .identified("outer")
+        .align(Align::Start, Align::Start);
+    let h = &mut Harness::new(ui, [100.0, 100.0], ());
+    assert_eq!(placed(h, "outer").1, [32.0, 28.0]);
+}
+
+#[test]
+fn insets_too_big_for_the_space_shrink_in_proportion() {
+    let h = &mut Harness::new(filler("inner").padded(Insets::new(90.0, 0.0, 30.0, 0.0)), [100.0, 100.0], ());
+    assert_eq!(placed(h, "inner"), ([75.0, 0.0], [0.0, 100.0]));
+}
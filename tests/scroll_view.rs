@@ -0,0 +1,78 @@
+use gui::{
+    widget::{Button, Label, List, ScrollView},
+    event::Click,
+    harness::Harness,
+    MouseButton, Widget,
+};
+
+/// Ten rows of 40, each setting the data to its number when clicked, in a view 100 high with a 10 wide scrollbar, so it scrolls by up to 300.
+fn rows<'a>() -> impl Widget<'a, u32> {
+    let list = (0..10).fold(List::vertical(), |list, i| {
+        list.push_flex(Button::default_state()
+            .containing(Label::with_state(format!("Row {}", i)))
+            .on(Click, move |ctx| *ctx.data = i + 1)
+            .height(40.0), 0.0)
+    });
+    ScrollView::vertical().containing(list.identified("content"))
+}
+
+fn offset(h: &mut Harness<u32>) -> f32 {
+    h.bounds_of("content").unwrap().pos()[1]
+}
+
+#[test]
+fn the_wheel_scrolls_by_steps() {
+    let mut h = Harness::new(rows(), [100.0, 100.0], 0);
+    h.scroll([50.0, 50.0], [0.0, -1.0]);
+    assert_eq!(offset(&mut h), -40.0);
+    h.scroll([50.0, 50.0], [0.0, -2.0]);
+    assert_eq!(offset(&mut h), -120.0);
+    h.scroll([50.0, 50.0], [0.0, 1.0]);
+    assert_eq!(offset(&mut h), -80.0);
+}
+
+#[test]
+fn scrolling_stops_at_both_ends() {
+    let mut h = Harness::new(rows(), [100.0, 100.0], 0);
+    h.scroll([50.0, 50.0], [0.0, 1.0]);
+    assert_eq!(offset(&mut h), 0.0);
+    h.scroll([50.0, 50.0], [0.0, -100.0]);
+    assert_eq!(offset(&mut h), -300.0);
+    h.scroll([50.0, 50.0], [0.0, 100.0]);
+    assert_eq!(offset(&mut h), 0.0);
+}
+
+#[test]
+fn dragging_the_thumb_scrolls_in_proportion() {
+    // The thumb is a quarter of the track, leaving 75 to move along for 300 of scrolling
+    let mut h = Harness::new(rows(), [100.0, 100.0], 0);
+    h.drag([95.0, 10.0], [95.0, 47.5], MouseButton::Left);
+    assert_eq!(offset(&mut h), -150.0);
+    h.drag([95.0, 50.0], [95.0, 500.0], MouseButton::Left);
+    assert_eq!(offset(&mut h), -300.0);
+}
+
+#[test]
+fn content_is_clipped_to_the_viewport() {
+    let mut h = Harness::new(rows(), [100.0, 100.0], 0);
+    let dump = h.draw().dump();
+    let clip = dump.find("push_clip pos=(0, 0) size=(90, 100)").expect("the viewport should clip the content");
+    // Every row is drawn within the viewport's clip, before the scrollbar
+    assert!(dump.find("\"Row 0\"").unwrap() > clip);
+    assert!(dump.find("\"Row 9\"").unwrap() > clip);
+}
+
+#[test]
+fn content_scrolled_out_of_view_ignores_clicks() {
+    let ui = List::vertical()
+        .push_flex(rows().height(100.0), 0.0)
+        .push_flex(Label::with_state(""), 1.0);
+    let mut h = Harness::new(ui, [100.0, 200.0], 0);
+    // Row 3 lies below the viewport, under the label
+    h.click([20.0, 140.0], MouseButton::Left);
+    assert_eq!(*h.data(), 0);
+
+    h.scroll([20.0, 50.0], [0.0, -2.0]);
+    h.click([20.0, 60.0], MouseButton::Left);
+    assert_eq!(*h.data(), 4);
+}
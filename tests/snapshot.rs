@@ -0,0 +1,52 @@
+use gui::{
+    widget::{Button, Label, List, TextBox, Toggle},
+    event::Click,
+    harness::Harness,
+    snapshot::assert_snapshot,
+    Widget,
+};
+
+#[derive(Default)]
+struct Data {
+    name: String,
+    subscribed: bool,
+    clicks: usize,
+}
+
+fn form<'a>() -> impl Widget<'a, Data> {
+    List::<Data>::vertical()
+        .spacing(4.0)
+        .push(Label::<Data>::with_state("Name"))
+        .push(TextBox::<Data>::bind_state(|d| &mut d.name).identified("name"))
+        .push(Toggle::<Data>::bind_state(|d| &mut d.subscribed)
+            .containing(Label::<Data>::with_state("Subscribe").padded(4.0))
+            .identified("subscribe"))
+        .push(Button::<Data>::default_state()
+            .containing(Label::<Data>::with_state("Submit").padded(4.0))
+            .on(Click, |ctx| ctx.data.clicks += 1)
+            .identified("submit"))
+        .padded(8.0)
+}
+
+fn snapshot(name: &str) -> String {
+    format!("{}/tests/snapshots/{}.snap", env!("CARGO_MANIFEST_DIR"), name)
+}
+
+#[test]
+fn form_at_rest() {
+    let mut h = Harness::new(form(), [240.0, 200.0], Data::default());
+    assert_snapshot(snapshot("form_at_rest"), &h.draw().dump());
+}
+
+#[test]
+fn form_after_input() {
+    let mut h = Harness::new(form(), [240.0, 200.0], Data::default());
+    h.click_on("name");
+    h.type_text("Ada");
+    h.click_on("subscribe");
+    h.hover_on("submit");
+
+    assert_eq!(h.data().name, "Ada");
+    assert!(h.data().subscribed);
+    assert_snapshot(snapshot("form_after_input"), &h.draw().dump());
+}
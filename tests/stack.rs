@@ -0,0 +1,65 @@
+use gui::{
+    layout::Align,
+    widget::{Button, Label, Stack},
+    event::Click,
+    harness::Harness,
+    MouseButton, Widget,
+};
+
+#[derive(Default)]
+struct Data {
+    clicked: Vec<&'static str>,
+}
+
+fn button<'a>(name: &'static str) -> Button<'a, Data> {
+    Button::<Data>::default_state()
+        .containing(Label::with_state(name))
+        .on(Click, move |ctx| ctx.data.clicked.push(name))
+}
+
+#[test]
+fn only_the_top_layer_gets_a_click() {
+    let ui = Stack::new()
+        .push(button("bottom"))
+        .push(button("top"));
+    let mut h = Harness::new(ui, [100.0, 100.0], Data::default());
+    // Buttons are as big as their labels, so both cover the top left corner
+    h.click([5.0, 5.0], MouseButton::Left);
+    assert_eq!(h.data().clicked, vec!["top"]);
+}
+
+#[test]
+fn clicks_beside_a_smaller_top_layer_reach_the_one_beneath() {
+    let ui = Stack::new()
+        .push(button("bottom"))
+        .push_aligned(button("top").identified("top"), Align::End, Align::Start);
+    let mut h = Harness::new(ui, [100.0, 100.0], Data::default());
+    h.click_on("top");
+    h.click([5.0, 5.0], MouseButton::Left);
+    assert_eq!(h.data().clicked, vec!["top", "bottom"]);
+}
+
+#[test]
+fn later_layers_are_drawn_above() {
+    let ui = Stack::new()
+        .push(Label::with_state("bottom"))
+        .push(Label::with_state("top"));
+    let mut h = Harness::new(ui, [100.0, 100.0], Data::default());
+    let dump = h.draw().dump();
+    assert!(dump.find("\"bottom\"").unwrap() < dump.find("\"top\"").unwrap());
+}
+
+#[test]
+fn layers_are_aligned_separately() {
+    let label = |id: &str| Label::with_state("ab").identified(id);
+    let ui = Stack::new()
+        .push_aligned(label("start"), Align::Start, Align::Start)
+        .push_aligned(label("center"), Align::Center, Align::Center)
+        .push_aligned(label("end"), Align::End, Align::End);
+    let mut h = Harness::new(ui, [100.0, 100.0], Data::default());
+    // Labels are 9 wide per character and 20 high with the harness's metrics
+    assert_eq!(h.bounds_of("start").unwrap().pos(), [0.0, 0.0]);
+    assert_eq!(h.bounds_of("center").unwrap().pos(), [41.0, 40.0]);
+    assert_eq!(h.bounds_of("end").unwrap().pos(), [82.0, 80.0]);
+    assert_eq!(h.bounds_of("end").unwrap().size(), [18.0, 20.0]);
+}
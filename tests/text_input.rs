@@ -0,0 +1,80 @@
+use gui::{
+    widget::{Button, Label, List, Stack, TextArea, TextBox},
+    event::{KeyPress, KeyRelease},
+    harness::Harness,
+    Widget, Key, Modifiers, Theme,
+};
+
+#[derive(Default)]
+struct Data {
+    text: String,
+    presses: Vec<Key>,
+    releases: Vec<Key>,
+}
+
+fn area<'a>() -> impl Widget<'a, Data> {
+    List::vertical().push_flex(
+        TextArea::bind_state(|d: &mut Data| &mut d.text)
+            .rows(1, 20)
+            .identified("area"),
+        0.0,
+    )
+}
+
+fn rows_height(rows: usize) -> f32 {
+    let theme = Theme::default();
+    rows as f32 * theme.text_height + theme.padding * 2.0
+}
+
+#[test]
+fn text_area_asks_for_its_lines_before_first_draw() {
+    let text = "1\n2\n3\n4\n5\n6\n7".to_string();
+    let mut h = Harness::new(area(), [200.0, 400.0], Data { text, ..Data::default() });
+    assert_eq!(h.bounds_of("area").unwrap().size()[1], rows_height(7));
+}
+
+#[test]
+fn text_area_layout_follows_each_edit() {
+    let mut h = Harness::new(area(), [200.0, 400.0], Data::default());
+    h.click_on("area");
+    h.type_key(Key::Enter, Modifiers::default());
+    assert_eq!(h.bounds_of("area").unwrap().size()[1], rows_height(2));
+    h.type_key(Key::Enter, Modifiers::default());
+    assert_eq!(h.bounds_of("area").unwrap().size()[1], rows_height(3));
+}
+
+#[test]
+fn text_box_only_swallows_releases_of_keys_it_used() {
+    let list = List::vertical()
+        .push(TextBox::bind_state(|d: &mut Data| &mut d.text).identified("box"))
+        .on(KeyPress, |ctx| ctx.data.presses.push(ctx.event.key))
+        .on(KeyRelease, |ctx| ctx.data.releases.push(ctx.event.key));
+
+    let mut h = Harness::new(list, [200.0, 100.0], Data::default());
+    h.click_on("box");
+    h.type_key(Key::Home, Modifiers::default());
+    h.type_key(Key::Tab, Modifiers::default());
+    assert_eq!(h.data().presses, vec![Key::Tab]);
+    assert_eq!(h.data().releases, vec![Key::Tab]);
+}
+
+#[test]
+fn key_handlers_are_given_the_key_and_modifiers() {
+    let ctrl = Modifiers { ctrl: true, ..Modifiers::default() };
+    let ui = Stack::new()
+        .push(Button::<Data>::default_state()
+            .containing(Label::with_state("Go"))
+            .padded(4.0)
+            .on(KeyPress, move |ctx| {
+                assert_eq!(ctx.event.modifiers, ctrl);
+                assert!(!ctx.event.repeat);
+                ctx.data.presses.push(ctx.event.key);
+            }))
+        .on(KeyRelease, |ctx| ctx.data.releases.push(ctx.event.key));
+
+    let mut h = Harness::new(ui, [200.0, 100.0], Data::default());
+    h.type_key(Key::S, ctrl);
+    // The button handled the press, so only the release was left for the stack
+    assert_eq!(h.data().presses, vec![Key::S]);
+    assert_eq!(h.data().releases, vec![Key::S]);
+}
@@ -0,0 +1,47 @@
+use gui::{
+    layout::Align,
+    text::{TextMetrics, FixedMetrics},
+    widget::{Label, List, TextArea},
+    harness::Harness,
+    Widget,
+};
+use std::{cell::Cell, rc::Rc};
+
+/// Metrics twice as wide as `FixedMetrics`, that count how many characters they have been asked to measure.
+#[derive(Default)]
+struct Counting {
+    chars: Cell<usize>,
+}
+
+impl TextMetrics for Counting {
+    fn measure(&self, text: &str, height: f32) -> [f32; 2] {
+        self.chars.set(self.chars.get() + text.chars().count());
+        let [w, h] = FixedMetrics.measure(text, height);
+        [w * 2.0, h]
+    }
+}
+
+#[test]
+fn each_harness_measures_with_its_own_metrics() {
+    let label = || Label::<()>::with_state("Hello").identified("label").align(Align::Start, Align::Start);
+    let mut fixed = Harness::new(label(), [200.0, 100.0], ());
+    let mut wide = Harness::new(label(), [200.0, 100.0], ());
+    wide.set_metrics(Rc::new(Counting::default()));
+
+    let width = |h: &mut Harness<()>| h.bounds_of("label").unwrap().size()[0];
+    fixed.relayout();
+    assert_eq!(width(&mut wide), width(&mut fixed) * 2.0);
+}
+
+#[test]
+fn wrapping_measures_each_character_a_bounded_number_of_times() {
+    let text = "lorem ipsum dolor sit amet ".repeat(40);
+    let len = text.chars().count();
+    let area = List::vertical().push_flex(TextArea::<String>::bind_state(|s| s).rows(1, 1000), 0.0);
+    let mut h = Harness::new(area, [300.0, 2000.0], text);
+
+    let metrics = Rc::new(Counting::default());
+    h.set_metrics(metrics.clone());
+    assert!(metrics.chars.get() > 0);
+    assert!(metrics.chars.get() < len * 4, "measured {} characters of {}", metrics.chars.get(), len);
+}
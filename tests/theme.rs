@@ -0,0 +1,92 @@
+use gui::{
+    draw::Color,
+    layout::{Bounds, LayoutReq},
+    widget::{Button, Label, List, TextBox},
+    harness::Harness,
+    Env, Event, Response, Style, Theme, Widget, MouseButton,
+};
+use std::{cell::RefCell, rc::Rc};
+
+fn text_box<'a>() -> impl Widget<'a, String> {
+    List::vertical().push_flex(TextBox::<String>::bind_state(|s| s).identified("box"), 0.0)
+}
+
+fn fill_of(theme: &Theme) -> String {
+    format!("fill={}", theme.palette.surface)
+}
+
+#[test]
+fn each_harness_draws_with_its_own_theme() {
+    let mut light = Harness::new(text_box(), [200.0, 100.0], String::new());
+    let mut dark = Harness::new(text_box(), [200.0, 100.0], String::new());
+    dark.set_theme(Theme::dark());
+
+    assert!(light.draw().dump().contains(&fill_of(&Theme::light())));
+    assert!(dark.draw().dump().contains(&fill_of(&Theme::dark())));
+    assert!(!light.draw().dump().contains(&fill_of(&Theme::dark())));
+}
+
+#[test]
+fn changing_the_theme_lays_widgets_out_again() {
+    let mut h = Harness::new(text_box(), [200.0, 100.0], String::new());
+    assert_eq!(h.bounds_of("box").unwrap().size()[1], 28.0);
+    h.set_theme(Theme { text_height: 30.0, ..Theme::default() });
+    assert_eq!(h.bounds_of("box").unwrap().size()[1], 38.0);
+}
+
+#[test]
+fn styles_only_apply_to_their_subtree() {
+    let ui = List::<()>::vertical()
+        .push_flex(Label::with_state("Big").styled(Style::new().text_height(40.0)).identified("big"), 0.0)
+        .push_flex(Label::with_state("Small").identified("small"), 0.0);
+    let mut h = Harness::new(ui, [200.0, 100.0], ());
+    assert_eq!(h.bounds_of("big").unwrap().size()[1], 40.0);
+    assert_eq!(h.bounds_of("small").unwrap().size()[1], 20.0);
+}
+
+#[test]
+fn styled_backgrounds_also_colour_hovered_and_pressed_widgets() {
+    let style = Style::new().background(Color::RED);
+    let theme = style.apply(&Theme::default());
+    let ui = Button::<()>::default_state().styled(style).identified("button");
+    let mut h = Harness::new(ui, [100.0, 40.0], ());
+
+    assert!(h.draw().dump().contains(&format!("fill={}", Color::RED)));
+    h.hover_on("button");
+    assert!(h.draw().dump().contains(&format!("fill={}", theme.palette.hover)));
+    let pos = h.center_of("button");
+    h.mouse_down(pos, MouseButton::Left);
+    assert!(h.draw().dump().contains(&format!("fill={}", theme.palette.pressed)));
+    assert_ne!(theme.palette.hover, theme.palette.pressed);
+    assert_ne!(theme.palette.hover, Theme::default().palette.hover);
+}
+
+/// Keeps hold of the environment it is given on every event.
+struct EnvProbe(Rc<RefCell<Vec<Env>>>);
+
+impl<'a> Widget<'a, ()> for EnvProbe {
+    fn get_layout_req(&mut self, _data: &mut (), _env: &Env) -> LayoutReq {
+        LayoutReq::any()
+    }
+
+    fn handle(&mut self, _data: &mut (), env: &Env, _event: &Event, _bounds: Bounds, _resp: &mut Response) -> bool {
+        self.0.borrow_mut().push(env.clone());
+        false
+    }
+}
+
+#[test]
+fn styled_themes_are_only_derived_again_when_the_outer_theme_changes() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let ui = EnvProbe(seen.clone()).styled(Style::new().radius(2.0));
+    let mut h = Harness::new(ui, [100.0, 40.0], ());
+
+    h.cursor_move([1.0; 2]);
+    h.cursor_move([2.0; 2]);
+    h.set_theme(Theme::dark());
+    h.cursor_move([3.0; 2]);
+
+    let themes = seen.borrow().iter().map(|env| env.theme() as *const Theme).collect::<Vec<_>>();
+    assert_eq!(themes[0], themes[1]);
+    assert_ne!(themes[1], themes[2]);
+}